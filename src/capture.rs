@@ -0,0 +1,157 @@
+//! Module contains [`Capture`] that describes how [`ZVec`](crate::zvec::ZVec)'s elements are saved in operations.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+};
+
+use crate::{zvec::ZVecOperation, Zond};
+
+/// Saved representation of collection's element.
+///
+/// Clone is boxed, so other representations don't take as much memory as the element.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Captured<T> {
+    /// Clone of the element. Saved with [`Capture::value`].
+    Value(Box<T>),
+    /// Element formatted with [`Debug`]. Saved with [`Capture::debug`].
+    Debug(String),
+    /// Element's hash calculated with [`DefaultHasher`]. Saved with [`Capture::hash`].
+    Hash(u64),
+    /// Element's size in bytes, see [`std::mem::size_of_val`]. Saved with [`Capture::size`].
+    Size(usize),
+    /// Nothing is saved. Saved with [`Capture::none`].
+    None,
+}
+
+/// Saved representation of several collection's elements, e.g. of appended slice.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CapturedSlice<T> {
+    /// Each element is saved separately. Saved with [`Capture::value`], [`Capture::debug`] and [`Capture::hash`].
+    Items(Vec<Captured<T>>),
+    /// Total size of elements in bytes, see [`std::mem::size_of_val`]. Saved with [`Capture::size`].
+    Size(usize),
+    /// Only count of elements. Saved with [`Capture::none`].
+    Count(usize),
+}
+
+// How `Capture` saves several elements.
+#[derive(Clone, Copy)]
+enum SliceMode {
+    Items,
+    Size,
+    Count,
+}
+
+/// Describes how elements are saved in operations. Set with [`Zond::with_capture`].
+///
+/// The default one is [`Capture::none`] since it doesn't require anything from element's type.
+///
+/// # Example
+/// ```
+/// # use std::fmt::Debug;
+/// # use zond::{Capture, CollectionInfo, ZondHandler, Operations, OperationType, Zond, Policy, zvec::ZVecOperation};
+/// # struct HandlerImpl;
+/// # impl<T: OperationType + Debug> ZondHandler<T> for HandlerImpl {
+/// #     fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {}
+/// # }
+/// // Elements aren't cloned, only their debug representations are saved.
+/// let zond: Zond<ZVecOperation<Vec<u8>>> =
+///     Zond::new(HandlerImpl, Policy::on_drop_only()).with_capture(Capture::debug());
+/// ```
+pub struct Capture<T> {
+    capture: fn(&T) -> Captured<T>,
+    slice_mode: SliceMode,
+}
+
+impl<T> Capture<T> {
+    /// Elements are cloned.
+    pub fn value() -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            capture: |value| Captured::Value(Box::new(value.clone())),
+            slice_mode: SliceMode::Items,
+        }
+    }
+
+    /// Elements are formatted with [`Debug`].
+    pub fn debug() -> Self
+    where
+        T: Debug,
+    {
+        Self {
+            capture: |value| Captured::Debug(format!("{value:?}")),
+            slice_mode: SliceMode::Items,
+        }
+    }
+
+    /// Elements' hashes are saved.
+    pub fn hash() -> Self
+    where
+        T: Hash,
+    {
+        Self {
+            capture: |value| {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                Captured::Hash(hasher.finish())
+            },
+            slice_mode: SliceMode::Items,
+        }
+    }
+
+    /// Elements' sizes in bytes are saved. Heap memory owned by element is not counted.
+    pub fn size() -> Self {
+        Self {
+            capture: |value| Captured::Size(std::mem::size_of_val(value)),
+            slice_mode: SliceMode::Size,
+        }
+    }
+
+    /// Nothing is saved.
+    pub fn none() -> Self {
+        Self {
+            capture: |_| Captured::None,
+            slice_mode: SliceMode::Count,
+        }
+    }
+
+    pub(crate) fn capture(&self, value: &T) -> Captured<T> {
+        (self.capture)(value)
+    }
+
+    pub(crate) fn capture_all(&self, values: &[T]) -> CapturedSlice<T> {
+        match self.slice_mode {
+            SliceMode::Items => {
+                CapturedSlice::Items(values.iter().map(|value| self.capture(value)).collect())
+            }
+            SliceMode::Size => CapturedSlice::Size(std::mem::size_of_val(values)),
+            SliceMode::Count => CapturedSlice::Count(values.len()),
+        }
+    }
+}
+
+impl<T> Default for Capture<T> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T> Clone for Capture<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Capture<T> {}
+
+impl<T> Zond<ZVecOperation<T>> {
+    /// Sets how elements are saved in operations.
+    pub fn with_capture(mut self, capture: Capture<T>) -> Self {
+        self.capture = capture;
+        self
+    }
+}
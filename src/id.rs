@@ -0,0 +1,118 @@
+//! Module contains [`IdGenerator`] trait and its built-in implementation [`Ids`].
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+static ID_GENERATOR: AtomicUsize = AtomicUsize::new(0);
+
+/// Generates ids of collections, see [`CollectionInfo::get_id`](crate::CollectionInfo::get_id).
+///
+/// One generator instance is shared between all collections constructed with the same [`Zond`](crate::Zond) and its clones.
+///
+/// # Example
+/// ```
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use zond::IdGenerator;
+/// // Ids of collections are even numbers.
+/// struct EvenIds(AtomicUsize);
+///
+/// impl IdGenerator for EvenIds {
+///     fn next_id(&self) -> usize {
+///         self.0.fetch_add(2, Ordering::Relaxed)
+///     }
+/// }
+/// ```
+pub trait IdGenerator: Send + Sync {
+    /// Returns id for newly constructed collection.
+    fn next_id(&self) -> usize;
+}
+
+enum IdsInner {
+    // Counter shared by the whole process.
+    Global,
+    // Counter owned by generator.
+    Counter(AtomicUsize),
+    // Pseudo-random sequence produced by splitmix64 from given state.
+    Sequence(AtomicU64),
+}
+
+/// Built-in [`IdGenerator`].
+///
+/// # Example
+/// ```
+/// # use std::fmt::Debug;
+/// # use zond::{CollectionInfo, Ids, ZondHandler, Operations, OperationType, Zond, Policy, zvec::ZVecOperation};
+/// # struct HandlerImpl;
+/// # impl<T: OperationType + Debug> ZondHandler<T> for HandlerImpl {
+/// #     fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {}
+/// # }
+/// // Collections constructed with this zond and its clones get ids 0, 1, 2...
+/// // regardless of collections constructed elsewhere, e.g. in other tests.
+/// let zond: Zond<ZVecOperation<usize>> =
+///     Zond::new(HandlerImpl, Policy::on_drop_only()).with_id_generator(Ids::counter());
+/// ```
+pub struct Ids {
+    inner: IdsInner,
+}
+
+impl Ids {
+    /// Ids are taken from counter shared by the whole process, so they are unique among all collections.
+    /// It is used by default.
+    ///
+    /// *Attention*. Ids depend on order in which collections are constructed in all threads, e.g. on order of tests.
+    pub fn global() -> Self {
+        Self {
+            inner: IdsInner::Global,
+        }
+    }
+
+    /// Ids are taken from counter owned by this generator and start from 0.
+    /// They are unique only among collections constructed with the same [`Zond`](crate::Zond) and its clones.
+    pub fn counter() -> Self {
+        Self {
+            inner: IdsInner::Counter(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Ids are random, so collections can be told apart even across different processes, like with UUIDs.
+    /// Unlike UUIDs ids are only `usize` wide, so collisions are unlikely but not impossible.
+    pub fn random() -> Self {
+        // Each `RandomState` is created with new random keys.
+        Self::seeded(RandomState::new().build_hasher().finish())
+    }
+
+    /// Ids look random, but are the same for the same `seed` and order of constructed collections.
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            inner: IdsInner::Sequence(AtomicU64::new(seed)),
+        }
+    }
+}
+
+impl Default for Ids {
+    fn default() -> Self {
+        Self::global()
+    }
+}
+
+impl IdGenerator for Ids {
+    fn next_id(&self) -> usize {
+        match &self.inner {
+            IdsInner::Global => ID_GENERATOR.fetch_add(1, Ordering::Relaxed),
+            IdsInner::Counter(counter) => counter.fetch_add(1, Ordering::Relaxed),
+            IdsInner::Sequence(state) => {
+                // splitmix64: https://prng.di.unimi.it/splitmix64.c
+                const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+                let mut z = state
+                    .fetch_add(GAMMA, Ordering::Relaxed)
+                    .wrapping_add(GAMMA);
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+                (z ^ (z >> 31)) as usize
+            }
+        }
+    }
+}
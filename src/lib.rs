@@ -1,6 +1,17 @@
 //! Zond is crate with standard rust collections but with collecting statistics.
 //!
-//! Ok, maybe it contains only analogue of [`Vec`] - [`zvec::ZVec`]. And ok, `ZVec` contains only some part of `Vec` methods.
+//! Ok, maybe it contains only analogues of some std collections:
+//! - [`Vec`] - [`zvec::ZVec`];
+//! - [`VecDeque`](std::collections::VecDeque) - [`zvecdeque::ZVecDeque`];
+//! - [`HashMap`](std::collections::HashMap) - [`zhashmap::ZHashMap`];
+//! - [`HashSet`](std::collections::HashSet) - [`zhashset::ZHashSet`];
+//! - [`BTreeMap`](std::collections::BTreeMap) - [`zbtreemap::ZBTreeMap`];
+//! - [`BTreeSet`](std::collections::BTreeSet) - [`zbtreeset::ZBTreeSet`];
+//! - [`BinaryHeap`](std::collections::BinaryHeap) - [`zbinaryheap::ZBinaryHeap`];
+//! - [`LinkedList`](std::collections::LinkedList) - [`zlinkedlist::ZLinkedList`] (without cursors: they are unstable in std yet);
+//! - [`String`] - [`zstring::ZString`].
+//!
+//! And ok, they contain only some part of their std analogues' methods.
 //! But I made this just for fun. I don't know anyone who would really need this.
 //!
 //! # Example
@@ -9,19 +20,19 @@
 //!
 //! Constructors similar to their std analogues' constructors but have additional argument - struct [`Zond`] with two fields:
 //! 1. `zond_handler` of type [`ZondHandler`]. \
-//!  Trait object with single method that consumes two arguments: `id` as [`usize`] and `operations` as [`Operations`].
-//!  All operations handling is hapeppening here: you can save them to file or database, send to your server or just print to console.
-//! 2. `policy` of type [`Policy`]. \
-//!  Desribes the rules about when collected operations will handled by `zond_handler`.
+//!    Trait object with single method that consumes two arguments: `collection` as [`CollectionInfo`] and `operations` as [`Operations`].
+//!    All operations handling is hapeppening here: you can save them to file or database, send to your server or just print to console.
+//! 2. `policy` of type [`Policy`] or your own [`FlushPolicy`] implementation. \
+//!    Desribes the rules about when collected operations will handled by `zond_handler`.
 //! ```
 //! # use std::{fmt::Debug, num::NonZeroUsize};
-//! # use zond::{OperationType, ZondHandler, Operations, Zond, Policy, zvec::{ZVec, ZVecOperation}};
+//! # use zond::{Capture, CollectionInfo, OperationType, ZondHandler, Operations, Zond, Policy, zvec::{ZVec, ZVecOperation}};
 //! // So at first let's implement some ZondHandler. It will just print operations to stdout:
 //! struct HandlerImpl;
 //!
 //! impl<T: OperationType + Debug> ZondHandler<T> for HandlerImpl {
-//!     fn handle(&self, id: usize, operations: Operations<T>) {
-//!         println!("{id} collected");
+//!     fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {
+//!         println!("{} collected", collection.get_id());
 //!         operations
 //!             .iter()
 //!             .for_each(|v| println!("{:?}: {:?}", v.get_instant(), v.get_type()));
@@ -31,11 +42,12 @@
 //!
 //! # fn main() {
 //! // Next let's construct Zond with HandlerImpl handler and such a policy that operations will be handled after each three method calls.
-//! // It will handle operations for ZVec:
+//! // It will handle operations for ZVec and save clones of pushed elements:
 //! let zond: Zond<ZVecOperation<usize>> = Zond::new(
 //!     HandlerImpl,
 //!     Policy::on_count_operations(NonZeroUsize::new(3).unwrap()),
-//! );
+//! )
+//! .with_capture(Capture::value());
 //!
 //! // Next let's construct ZVec with zond variable:
 //! let mut zvec: ZVec<usize> = ZVec::new(zond);
@@ -55,44 +67,80 @@
 //! ```text
 //! 0 collected
 //! Instant { /* */ }: New
-//! Instant { /* */ }: Push { value: 1 }
-//! Instant { /* */ }: Push { value: 2 }
+//! Instant { /* */ }: Push { value: Value(1) }
+//! Instant { /* */ }: Push { value: Value(2) }
 //!
 //! 0 collected
-//! Instant { /* */ }: Push { value: 5 }
-//! Instant { /* */ }: Push { value: 5 }
+//! Instant { /* */ }: Push { value: Value(5) }
+//! Instant { /* */ }: Push { value: Value(5) }
 //! Instant { /* */ }: ExtendFromWithin { src_start_bound: Included(1), src_end_bound: Unbounded }
 //!
 //! 0 collected
-//! Instant { /* */ }: Dedup
+//! Instant { /* */ }: Dedup { removed: 2 }
 //! ```
 //!
 //! As you can see, operations always being handled when dropping.
 
 use std::{
-    cell::RefCell,
-    sync::{
-        atomic::{AtomicUsize, Ordering},
-        Arc,
-    },
-    time::Instant,
+    backtrace::Backtrace,
+    collections::{BTreeMap, VecDeque},
+    num::NonZeroUsize,
+    panic::Location,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    thread::{self, Thread, ThreadId},
+    time::{Duration, Instant, SystemTime},
 };
 
-pub use policy::Policy;
-use policy::PolicyInner;
+pub use capture::{Capture, Captured, CapturedSlice};
+pub use id::{IdGenerator, Ids};
+pub use policy::{CollectionHandle, FlushContext, FlushPolicy, IntervalPolicy, Policy};
+pub use summary::{ArgumentSummary, KindSummary, ZondSummary};
 
+mod capture;
+mod id;
 mod policy;
+mod summary;
+pub mod zbinaryheap;
+pub mod zbtreemap;
+pub mod zbtreeset;
+pub mod zhashmap;
+pub mod zhashset;
+pub mod zlinkedlist;
+pub mod zslice;
+pub mod zstring;
 pub mod zvec;
-
-static ID_GENERATOR: AtomicUsize = AtomicUsize::new(0);
+pub mod zvecdeque;
 
 /// Helper trait for constrainting generic types in other structs and traits. \
-/// `OperationType` unites multiple collection-specific enums. But in fact only [`ZVec`](zvec::ZVec)'s [`ZVecOperation`](zvec::ZVecOperation).
-pub trait OperationType {}
+/// `OperationType` unites multiple collection-specific enums, e.g. [`ZVecOperation`](zvec::ZVecOperation)
+/// or [`ZHashMapOperation`](zhashmap::ZHashMapOperation).
+pub trait OperationType {
+    /// Collection-specific settings of how operation's values are saved, e.g. [`Capture`] for [`ZVecOperation`](zvec::ZVecOperation).
+    /// Set to `()` if there are no such settings.
+    type Capture: Clone + Default + Send + Sync;
+
+    /// Name of operation type, e.g. `"Push"` for [`ZVecOperation::Push`](zvec::ZVecOperation::Push).
+    fn kind(&self) -> &'static str;
+
+    /// Calls `visitor` with name and value of each numeric argument, e.g. `("additional", 10)` for
+    /// [`ZVecOperation::Reserve`](zvec::ZVecOperation::Reserve). Used to build [`ZondSummary`].
+    ///
+    /// Default implementation has no arguments.
+    fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        let _ = visitor;
+    }
+}
 
-/// Describes one single operation with collection: time when it happened and operation type.
+/// Describes one single operation with collection: time when it happened, how long it took and operation type.
 pub struct Operation<T: OperationType> {
     instant: Instant,
+    system_time: Option<SystemTime>,
+    sequence: u64,
+    thread: Option<Thread>,
+    duration: Option<Duration>,
+    snapshot: Option<Snapshot>,
+    location: Option<&'static Location<'static>>,
+    backtrace: Option<Backtrace>,
     operation_type: T,
 }
 
@@ -109,6 +157,13 @@ impl<T: OperationType> Operation<T> {
     pub fn new(operation_type: T) -> Self {
         Self {
             instant: Instant::now(),
+            system_time: None,
+            sequence: 0,
+            thread: None,
+            duration: None,
+            snapshot: None,
+            location: None,
+            backtrace: None,
             operation_type,
         }
     }
@@ -118,10 +173,85 @@ impl<T: OperationType> Operation<T> {
         &self.instant
     }
 
+    /// Get wall-clock time when operation happened. It is `None` if [`Zond::with_system_time`] isn't used.
+    pub fn get_system_time(&self) -> Option<SystemTime> {
+        self.system_time
+    }
+
+    /// Get number of operation in its collection. Numbers start from 0 and follow the order in which operations were saved,
+    /// so gaps mean that some operations were dropped, see [`OverflowStrategy`].
+    pub fn get_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Get id of thread that called collection's method. It is `None` if [`Zond::with_threads`] isn't used.
+    pub fn get_thread_id(&self) -> Option<ThreadId> {
+        self.thread.as_ref().map(Thread::id)
+    }
+
+    /// Get name of thread that called collection's method.
+    /// It is `None` if [`Zond::with_threads`] isn't used or thread is unnamed.
+    pub fn get_thread_name(&self) -> Option<&str> {
+        self.thread.as_ref().and_then(Thread::name)
+    }
+
     /// Get operation type.
     pub fn get_type(&self) -> &T {
         &self.operation_type
     }
+
+    /// Get how long the method call took. It is `None` if [`Zond::with_duration`] isn't used
+    /// or collection doesn't support measuring. Currently only [`ZVec`](zvec::ZVec) and its slices support it.
+    pub fn get_duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Get collection's length and capacity before and after the operation. It is `None` if [`Zond::with_snapshots`] isn't used
+    /// or operation doesn't change collection. Currently only [`ZVec`](zvec::ZVec) supports it.
+    pub fn get_snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot.as_ref()
+    }
+
+    /// Get location in your code where collection's method was called. It is `None` if [`Zond::with_locations`] isn't used.
+    pub fn get_location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+
+    /// Get backtrace of collection's method call. It is `None` if [`Zond::with_backtraces`] isn't used.
+    pub fn get_backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_ref()
+    }
+}
+
+/// Collection's length and capacity before and after operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot {
+    pub(crate) len_before: usize,
+    pub(crate) capacity_before: usize,
+    pub(crate) len_after: usize,
+    pub(crate) capacity_after: usize,
+}
+
+impl Snapshot {
+    /// Get length before operation.
+    pub fn get_len_before(&self) -> usize {
+        self.len_before
+    }
+
+    /// Get capacity before operation.
+    pub fn get_capacity_before(&self) -> usize {
+        self.capacity_before
+    }
+
+    /// Get length after operation.
+    pub fn get_len_after(&self) -> usize {
+        self.len_after
+    }
+
+    /// Get capacity after operation.
+    pub fn get_capacity_after(&self) -> usize {
+        self.capacity_after
+    }
 }
 
 /// Just type alias for more convenient types declaring in other places.
@@ -129,45 +259,138 @@ pub type Operations<T> = Vec<Operation<T>>;
 
 /// Provides function that handle all operations with collection.
 ///
+/// Handler is shared between collections that may live in different threads, so it must be `Send` and `Sync`.
+///
 /// # Example
 /// ```no_run
 /// # use std::fmt::Debug;
-/// # use zond::{ZondHandler, Operations, OperationType};
+/// # use zond::{CollectionInfo, ZondHandler, Operations, OperationType};
 /// struct HandlerImpl;
 ///
 /// impl<T: OperationType + Debug> ZondHandler<T> for HandlerImpl {
-///     fn handle(&self, id: usize, operations: Operations<T>) {
+///     fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {
 ///         for operation in operations {
 ///             println!("{:?}", operation.get_type());
 ///         }
 ///     }
 /// }
 /// ```
-pub trait ZondHandler<T: OperationType> {
-    /// `id` is used to distinguish between different collection instances' operations.
+pub trait ZondHandler<T: OperationType>: Send + Sync {
+    /// `collection` is used to distinguish between different collection instances' operations.
     ///
     /// `operations` is just operations.
-    fn handle(&self, id: usize, operations: Operations<T>);
+    fn handle(&self, collection: &CollectionInfo, operations: Operations<T>);
+
+    /// Called right after [`handle`](ZondHandler::handle) if some operations weren't saved because of [`Zond::with_buffer_limit`].
+    /// Default implementation does nothing.
+    fn handle_dropped(&self, collection: &CollectionInfo, dropped: DroppedOperations) {
+        let _ = (collection, dropped);
+    }
+
+    /// Called instead of [`handle`](ZondHandler::handle) if [`Zond::with_summary`] is used.
+    /// Default implementation does nothing.
+    ///
+    /// `summary` is reset and reused after handling, so clone it if you need to keep it.
+    fn handle_summary(&self, collection: &CollectionInfo, summary: &ZondSummary) {
+        let _ = (collection, summary);
+    }
+}
+
+/// Identity of collection instance whose operations are handled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionInfo {
+    id: usize,
+    name: Option<String>,
+    labels: BTreeMap<String, String>,
+}
+
+impl CollectionInfo {
+    /// Get id of collection instance. Its uniqueness depends on [`Zond::with_id_generator`].
+    pub fn get_id(&self) -> usize {
+        self.id
+    }
+
+    /// Get name given by [`Zond::with_name`].
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get labels given by [`Zond::with_label`].
+    pub fn get_labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+}
+
+/// Operations that weren't saved since the last handling because buffer was full.
+#[derive(Debug, Clone, Default)]
+pub struct DroppedOperations {
+    count: usize,
+    kinds: BTreeMap<&'static str, usize>,
+}
+
+impl DroppedOperations {
+    /// Get count of dropped operations.
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+
+    /// Get count of dropped operations of each [`kind`](OperationType::kind).
+    /// Filled only with [`OverflowStrategy::Aggregate`].
+    pub fn get_kinds(&self) -> &BTreeMap<&'static str, usize> {
+        &self.kinds
+    }
+}
+
+/// Describes what to do with new operation when collection's buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowStrategy {
+    /// Handle operations as soon as buffer is full, regardless of policy.
+    FlushEarly,
+    /// Drop the oldest operation to make room for the new one.
+    DropOldest,
+    /// Drop the new operation.
+    DropNewest,
+    /// Drop the new operation, but count it by its [`kind`](OperationType::kind).
+    Aggregate,
+}
+
+#[derive(Clone, Copy)]
+struct BufferLimit {
+    max_operations: usize,
+    strategy: OverflowStrategy,
 }
 
 /// Struct that controls how and when to handle operations.
-#[derive(Clone)]
 pub struct Zond<T: OperationType> {
     zond_handler: Arc<dyn ZondHandler<T>>,
-    policy: Policy,
+    policy: Arc<dyn FlushPolicy<T>>,
+    buffer_limit: Option<BufferLimit>,
+    summary: bool,
+    capture: T::Capture,
+    duration: bool,
+    snapshots: bool,
+    locations: bool,
+    backtraces: bool,
+    system_time: bool,
+    threads: bool,
+    name: Option<String>,
+    labels: BTreeMap<String, String>,
+    id_generator: Arc<dyn IdGenerator>,
 }
 
 impl<T: OperationType> Zond<T> {
     /// Constructs a new `Zond<T>`
     ///
+    /// `policy` is either built-in [`Policy`] or your own [`FlushPolicy`] implementation.
+    ///
     /// # Example
     /// ```
     /// # use std::fmt::Debug;
-    /// # use zond::{ZondHandler, Operations, OperationType, Zond, Policy, zvec::ZVecOperation};
+    /// # use zond::{CollectionInfo, ZondHandler, Operations, OperationType, Zond, Policy, zvec::ZVecOperation};
     /// struct HandlerImpl;
     ///
     /// impl<T: OperationType + Debug> ZondHandler<T> for HandlerImpl {
-    ///     fn handle(&self, id: usize, operations: Operations<T>) {
+    ///     fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {
     ///         for operation in operations {
     ///             println!("{:?}", operation.get_type());
     ///         }
@@ -178,76 +401,517 @@ impl<T: OperationType> Zond<T> {
     ///     let zond: Zond<ZVecOperation<usize>> = Zond::new(HandlerImpl, Policy::on_drop_only());        
     /// }
     /// ```
-    pub fn new(zond_handler: impl ZondHandler<T> + 'static, policy: Policy) -> Self {
+    pub fn new(
+        zond_handler: impl ZondHandler<T> + 'static,
+        policy: impl FlushPolicy<T> + 'static,
+    ) -> Self {
         Self {
             zond_handler: Arc::new(zond_handler),
-            policy,
+            policy: Arc::new(policy),
+            buffer_limit: None,
+            summary: false,
+            capture: T::Capture::default(),
+            duration: false,
+            snapshots: false,
+            locations: false,
+            backtraces: false,
+            system_time: false,
+            threads: false,
+            name: None,
+            labels: BTreeMap::new(),
+            id_generator: Arc::new(Ids::default()),
         }
     }
+
+    /// Limits count of operations that collection keeps between handlings.
+    /// When buffer is full, new operations are processed according to `strategy`.
+    ///
+    /// Dropped operations are reported via [`ZondHandler::handle_dropped`].
+    ///
+    /// # Example
+    /// ```
+    /// # use std::{fmt::Debug, num::NonZeroUsize};
+    /// # use zond::{CollectionInfo, ZondHandler, Operations, OperationType, OverflowStrategy, Zond, Policy, zvec::ZVecOperation};
+    /// # struct HandlerImpl;
+    /// # impl<T: OperationType + Debug> ZondHandler<T> for HandlerImpl {
+    /// #     fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {}
+    /// # }
+    /// // Keep only the last 1000 operations.
+    /// let zond: Zond<ZVecOperation<usize>> = Zond::new(HandlerImpl, Policy::on_drop_only())
+    ///     .with_buffer_limit(NonZeroUsize::new(1000).unwrap(), OverflowStrategy::DropOldest);
+    /// ```
+    pub fn with_buffer_limit(
+        mut self,
+        max_operations: NonZeroUsize,
+        strategy: OverflowStrategy,
+    ) -> Self {
+        self.buffer_limit = Some(BufferLimit {
+            max_operations: max_operations.get(),
+            strategy,
+        });
+        self
+    }
+
+    /// Operations aren't saved at all. Instead they are counted by [`kind`](OperationType::kind)
+    /// with their numeric arguments' sums, minimums and maximums. The result is passed to [`ZondHandler::handle_summary`].
+    ///
+    /// Buffer limit is ignored in this mode since summary's size doesn't depend on count of operations.
+    /// Everything else that would be thrown away with operations isn't collected either:
+    /// captured values, durations, snapshots, locations, backtraces, wall-clock time and threads.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::fmt::Debug;
+    /// # use zond::{CollectionInfo, ZondHandler, Operations, OperationType, Zond, ZondSummary, Policy, zvec::ZVecOperation};
+    /// struct HandlerImpl;
+    ///
+    /// impl<T: OperationType> ZondHandler<T> for HandlerImpl {
+    ///     fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {}
+    ///
+    ///     fn handle_summary(&self, collection: &CollectionInfo, summary: &ZondSummary) {
+    ///         println!("{}: {summary:?}", collection.get_id());
+    ///     }
+    /// }
+    ///
+    /// let zond: Zond<ZVecOperation<usize>> = Zond::new(HandlerImpl, Policy::on_drop_only()).with_summary();
+    /// ```
+    pub fn with_summary(mut self) -> Self {
+        self.summary = true;
+        self
+    }
+
+    /// Measures how long each method call takes, see [`Operation::get_duration`].
+    /// It is off by default since measuring adds some overhead to each call.
+    pub fn with_duration(mut self) -> Self {
+        self.duration = true;
+        self
+    }
+
+    /// Saves collection's length and capacity before and after each operation that changes collection, see [`Operation::get_snapshot`].
+    /// Collections also save their specific events derived from snapshots, e.g. [`ZVecOperation::Reallocated`](zvec::ZVecOperation::Reallocated).
+    pub fn with_snapshots(mut self) -> Self {
+        self.snapshots = true;
+        self
+    }
+
+    /// Saves location in your code where collection's method was called, see [`Operation::get_location`].
+    ///
+    /// *Attention*. Operations saved on drop, e.g. [`ZVecOperation::IterEnd`](zvec::ZVecOperation::IterEnd),
+    /// point to this crate's code since `Drop` can't track its caller.
+    pub fn with_locations(mut self) -> Self {
+        self.locations = true;
+        self
+    }
+
+    /// Saves full backtrace of each collection's method call, see [`Operation::get_backtrace`].
+    /// Backtraces are captured regardless of `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE` variables, so it is much slower than [`Zond::with_locations`].
+    pub fn with_backtraces(mut self) -> Self {
+        self.backtraces = true;
+        self
+    }
+
+    /// Saves wall-clock time of each operation, see [`Operation::get_system_time`].
+    /// Unlike [`Instant`] it can be serialized or compared with timestamps of other processes.
+    pub fn with_system_time(mut self) -> Self {
+        self.system_time = true;
+        self
+    }
+
+    /// Saves thread that called collection's method, see [`Operation::get_thread_id`] and [`Operation::get_thread_name`].
+    pub fn with_threads(mut self) -> Self {
+        self.threads = true;
+        self
+    }
+
+    /// Gives human-readable name to collections constructed with this `Zond`, see [`CollectionInfo::get_name`].
+    /// Name isn't required to be unique: e.g. all caches of the same kind can share it.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::fmt::Debug;
+    /// # use zond::{CollectionInfo, ZondHandler, Operations, OperationType, Zond, Policy, zvec::ZVecOperation};
+    /// struct HandlerImpl;
+    ///
+    /// impl<T: OperationType> ZondHandler<T> for HandlerImpl {
+    ///     fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {
+    ///         let name = collection.get_name().unwrap_or("unnamed");
+    ///         let region = collection.get_labels().get("region").map_or("", String::as_str);
+    ///         println!("{name} ({region}): {} operations", operations.len());
+    ///     }
+    /// }
+    ///
+    /// let zond: Zond<ZVecOperation<usize>> = Zond::new(HandlerImpl, Policy::on_drop_only())
+    ///     .with_name("user_cache")
+    ///     .with_label("region", "eu");
+    /// ```
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds label to collections constructed with this `Zond`, see [`CollectionInfo::get_labels`].
+    /// Label with the same key is replaced.
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets how ids of collections are generated. By default it is [`Ids::global`].
+    ///
+    /// `id_generator` is either built-in [`Ids`] or your own [`IdGenerator`] implementation.
+    pub fn with_id_generator(mut self, id_generator: impl IdGenerator + 'static) -> Self {
+        self.id_generator = Arc::new(id_generator);
+        self
+    }
+}
+
+// Derived `Clone` would require operation type to be `Clone`, but it isn't needed to clone `Zond`.
+impl<T: OperationType> Clone for Zond<T> {
+    fn clone(&self) -> Self {
+        Self {
+            zond_handler: Arc::clone(&self.zond_handler),
+            policy: Arc::clone(&self.policy),
+            buffer_limit: self.buffer_limit,
+            summary: self.summary,
+            capture: self.capture.clone(),
+            duration: self.duration,
+            snapshots: self.snapshots,
+            locations: self.locations,
+            backtraces: self.backtraces,
+            system_time: self.system_time,
+            threads: self.threads,
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+            id_generator: Arc::clone(&self.id_generator),
+        }
+    }
+}
+
+// Collected operations and time of their last handling. They are always changed together under one lock.
+struct Buffer<T: OperationType> {
+    operations: VecDeque<Operation<T>>,
+    dropped: DroppedOperations,
+    // `Some` only if `Zond::with_summary` is used. Then operations aren't saved.
+    summary: Option<ZondSummary>,
+    // Summary returned after handling. It replaces the taken one, so its kinds aren't allocated again.
+    spare_summary: Option<ZondSummary>,
+    last_flush: Instant,
+    next_sequence: u64,
+    // Collection is dropped and its last operations are taken.
+    closed: bool,
+}
+
+// What was taken from `Buffer` to be handled.
+struct Taken<T: OperationType> {
+    operations: Operations<T>,
+    dropped: DroppedOperations,
+    summary: Option<ZondSummary>,
+}
+
+impl<T: OperationType> Buffer<T> {
+    // Saves operation accordingly to limit. Operation gets its sequence number here, so numbers follow the order in buffer.
+    fn push(&mut self, mut operation: Operation<T>, limit: Option<BufferLimit>) {
+        operation.sequence = self.next_sequence;
+        self.next_sequence += 1;
+        if let Some(summary) = &mut self.summary {
+            summary.add(operation.get_type());
+            return;
+        }
+        if let Some(limit) = limit.filter(|limit| self.operations.len() >= limit.max_operations) {
+            match limit.strategy {
+                OverflowStrategy::FlushEarly => {}
+                OverflowStrategy::DropOldest => {
+                    self.operations.pop_front();
+                    self.dropped.count += 1;
+                }
+                OverflowStrategy::DropNewest => {
+                    self.dropped.count += 1;
+                    return;
+                }
+                OverflowStrategy::Aggregate => {
+                    self.dropped.count += 1;
+                    *self
+                        .dropped
+                        .kinds
+                        .entry(operation.get_type().kind())
+                        .or_default() += 1;
+                    return;
+                }
+            }
+        }
+        self.operations.push_back(operation);
+    }
+
+    fn len(&self) -> usize {
+        match &self.summary {
+            Some(summary) => summary.get_count(),
+            None => self.operations.len() + self.dropped.count,
+        }
+    }
+
+    fn take(&mut self) -> Taken<T> {
+        self.last_flush = Instant::now();
+        Taken {
+            operations: std::mem::take(&mut self.operations).into(),
+            dropped: std::mem::take(&mut self.dropped),
+            summary: self.summary.as_mut().map(|summary| {
+                std::mem::replace(summary, self.spare_summary.take().unwrap_or_default())
+            }),
+        }
+    }
+}
+
+// Part of `ZondCollection` that can be shared with policies, e.g. with `IntervalPolicy`'s background thread.
+pub(crate) struct SharedBuffer<T: OperationType> {
+    info: CollectionInfo,
+    buffer: Mutex<Buffer<T>>,
+    // Held while operations are taken and delivered, so they can't be delivered out of order
+    // by collection's methods, by policies like `IntervalPolicy` and on drop.
+    delivery: Mutex<()>,
+    zond: Zond<T>,
+}
+
+impl<T: OperationType> SharedBuffer<T> {
+    // Pass taken operations to handler. Must be called without holding the lock, so handler can't block other threads' operations.
+    fn deliver(&self, taken: Taken<T>) {
+        let handler = &self.zond.zond_handler;
+        if let Some(mut summary) = taken.summary {
+            handler.handle_summary(&self.info, &summary);
+            summary.reset();
+            self.lock_buffer().spare_summary = Some(summary);
+            return;
+        }
+        handler.handle(&self.info, taken.operations);
+        if taken.dropped.count > 0 {
+            handler.handle_dropped(&self.info, taken.dropped);
+        }
+    }
+
+    // It guards no data, so it is fine to use it after handler's panic.
+    fn lock_delivery(&self) -> MutexGuard<'_, ()> {
+        self.delivery.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Buffer is consistent after any panic under the lock, so collection keeps working and can be dropped while unwinding.
+    fn lock_buffer(&self) -> MutexGuard<'_, Buffer<T>> {
+        self.buffer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Force handle collected operations. Called once when collection is dropped.
+    fn close(&self) {
+        let _delivery = self.lock_delivery();
+        let taken = {
+            let mut buffer = self.lock_buffer();
+            buffer.closed = true;
+            buffer.take()
+        };
+        self.deliver(taken);
+    }
+
+    // Handle collected operations only if there are any and collection isn't closed.
+    fn handle_non_empty(&self) {
+        let _delivery = self.lock_delivery();
+        let taken = {
+            let mut buffer = self.lock_buffer();
+            if buffer.closed || buffer.len() == 0 {
+                return;
+            }
+            buffer.take()
+        };
+        self.deliver(taken);
+    }
+}
+
+// Start of method call that is being measured.
+pub(crate) struct Timer {
+    start: Instant,
+    measure: bool,
+    call_site: CallSite,
+}
+
+// Where, when and from which thread collection's method was called.
+struct CallSite {
+    location: Option<&'static Location<'static>>,
+    backtrace: Option<Backtrace>,
+    system_time: Option<SystemTime>,
+    thread: Option<Thread>,
 }
 
 // Crucial part of the crate. This struct contains all other structs, trait object and enums that take part in storing and handling operations. \
 // Must be aggregated in structs that implement some collection's functionality.
+// It is `Send` and `Sync` whenever `T` is `Send`, so collections that aggregate it can be used from multiple threads.
 pub(crate) struct ZondCollection<T: OperationType> {
-    id: usize,
-    operations: RefCell<Operations<T>>,
-    zond: Zond<T>,
+    shared: Arc<SharedBuffer<T>>,
 }
 
 impl<T: OperationType> ZondCollection<T> {
     pub(crate) fn new(zond: Zond<T>) -> Self {
-        Self {
-            id: ID_GENERATOR.fetch_add(1, Ordering::Relaxed),
-            operations: RefCell::default(),
+        // Operations are thrown away after they are summarized, so nothing else is collected.
+        let zond = if zond.summary {
+            Zond {
+                capture: T::Capture::default(),
+                duration: false,
+                snapshots: false,
+                locations: false,
+                backtraces: false,
+                system_time: false,
+                threads: false,
+                ..zond
+            }
+        } else {
+            zond
+        };
+        let shared = Arc::new(SharedBuffer {
+            info: CollectionInfo {
+                id: zond.id_generator.next_id(),
+                name: zond.name.clone(),
+                labels: zond.labels.clone(),
+            },
+            buffer: Mutex::new(Buffer {
+                operations: VecDeque::new(),
+                dropped: DroppedOperations::default(),
+                summary: zond.summary.then(ZondSummary::default),
+                spare_summary: None,
+                last_flush: Instant::now(),
+                next_sequence: 0,
+                closed: false,
+            }),
+            delivery: Mutex::new(()),
             zond,
+        });
+        shared
+            .zond
+            .policy
+            .register(CollectionHandle::new(Arc::downgrade(&shared)));
+        Self { shared }
+    }
+
+    pub(crate) fn id(&self) -> usize {
+        self.shared.info.id
+    }
+
+    // `Zond` for collections derived from this one.
+    pub(crate) fn zond(&self) -> Zond<T> {
+        self.shared.zond.clone()
+    }
+
+    pub(crate) fn capture(&self) -> &T::Capture {
+        &self.shared.zond.capture
+    }
+
+    pub(crate) fn snapshots(&self) -> bool {
+        self.shared.zond.snapshots
+    }
+
+    // All methods that save operations are `#[track_caller]`, so the location is in user's code.
+    #[track_caller]
+    fn call_site(&self) -> CallSite {
+        let zond = &self.shared.zond;
+        CallSite {
+            // `then(Location::caller)` would point to `bool::then`.
+            location: if zond.locations {
+                Some(Location::caller())
+            } else {
+                None
+            },
+            backtrace: zond.backtraces.then(Backtrace::force_capture),
+            system_time: zond.system_time.then(SystemTime::now),
+            thread: zond.threads.then(thread::current),
         }
     }
 
-    // Force handle collected operations.
-    pub(crate) fn handle(&self) {
-        let operations = self.operations.replace(Vec::new());
-        self.zond.zond_handler.handle(self.id, operations);
-    }
-
-    // Check handling policy and, if accordingly to them operations should be handled, handle operations.
-    pub(crate) fn try_handle(&self) {
-        match &self.zond.policy.inner {
-            PolicyInner::OnCountOperations {
-                current_operations,
-                max_operations,
-            } => {
-                let mut current_operations = current_operations.borrow_mut();
-                if max_operations - 1 == *current_operations {
-                    *current_operations = 0;
-                    self.handle()
-                } else {
-                    *current_operations += 1;
-                }
-            }
-            PolicyInner::LessOften {
-                duration,
-                last_collect,
-            } => {
-                let mut last_collect = last_collect.borrow_mut();
-                let now = Instant::now();
-                if now.duration_since(*last_collect) > *duration {
-                    *last_collect = now;
-                    self.handle()
-                }
-            }
-            PolicyInner::OnDropOnly => (),
+    // Starts measuring method call. Must be passed to `push_timed_operation` after the call.
+    #[track_caller]
+    pub(crate) fn start_timer(&self) -> Timer {
+        Timer {
+            start: Instant::now(),
+            measure: self.shared.zond.duration,
+            call_site: self.call_site(),
         }
     }
 
-    // Push single operation to store and handle all of them if they should be handled.
+    // Calls `call` and saves `operation` with the call's duration.
+    #[track_caller]
+    pub(crate) fn timed<R>(&self, operation: T, call: impl FnOnce() -> R) -> R {
+        let timer = self.start_timer();
+        let result = call();
+        self.push_timed_operation(operation, timer, None);
+        result
+    }
+
+    #[track_caller]
     pub(crate) fn push_operation(&self, operation: T) {
-        self.operations.borrow_mut().push(Operation::new(operation));
-        self.try_handle();
+        let CallSite {
+            location,
+            backtrace,
+            system_time,
+            thread,
+        } = self.call_site();
+        self.push(Operation {
+            system_time,
+            thread,
+            location,
+            backtrace,
+            ..Operation::new(operation)
+        });
+    }
+
+    // Timer's start becomes operation's instant.
+    pub(crate) fn push_timed_operation(
+        &self,
+        operation: T,
+        timer: Timer,
+        snapshot: Option<Snapshot>,
+    ) {
+        self.push(Operation {
+            instant: timer.start,
+            system_time: timer.call_site.system_time,
+            sequence: 0,
+            thread: timer.call_site.thread,
+            duration: timer.measure.then(|| timer.start.elapsed()),
+            snapshot,
+            location: timer.call_site.location,
+            backtrace: timer.call_site.backtrace,
+            operation_type: operation,
+        });
+    }
+
+    // Push single operation to store and, if accordingly to policy operations should be handled, handle all of them.
+    fn push(&self, mut operation: Operation<T>) {
+        let shared = &*self.shared;
+        let limit = shared.zond.buffer_limit;
+        // Policy is user's code, so it is checked on copied values without holding the lock:
+        // its panic mustn't poison the buffer and it may flush the collection itself.
+        let (buffered_operations, since_last_flush, buffer_full) = {
+            let buffer = shared.lock_buffer();
+            operation.sequence = buffer.next_sequence;
+            let buffer_full = limit.is_some_and(|limit| {
+                limit.strategy == OverflowStrategy::FlushEarly
+                    && buffer.operations.len() + 1 >= limit.max_operations
+            });
+            (buffer.len() + 1, buffer.last_flush.elapsed(), buffer_full)
+        };
+        let context = FlushContext::new(buffered_operations, since_last_flush, &operation);
+        if !buffer_full && !shared.zond.policy.should_flush(&context) {
+            shared.lock_buffer().push(operation, limit);
+            return;
+        }
+        // Locks are taken in the same order as in `close` and `handle_non_empty`.
+        let _delivery = shared.lock_delivery();
+        let taken = {
+            let mut buffer = shared.lock_buffer();
+            buffer.push(operation, limit);
+            if buffer.closed {
+                return;
+            }
+            buffer.take()
+        };
+        shared.deliver(taken);
     }
 }
 
 impl<T: OperationType> Drop for ZondCollection<T> {
     fn drop(&mut self) {
-        self.handle();
+        self.shared.close();
     }
 }
@@ -1,46 +1,180 @@
-//! Module contains [`Policy`] struct with its variants and its variants' metadata.
+//! Module contains [`FlushPolicy`] trait, its built-in implementations [`Policy`] and [`IntervalPolicy`] and their metadata.
 
 use std::{
-    cell::RefCell,
     num::NonZeroUsize,
-    time::{Duration, Instant},
+    ops::Not,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex, Weak},
+    thread,
+    time::Duration,
 };
 
+use crate::{Operation, OperationType, SharedBuffer};
+
+/// Describes rules when collected operations will be handled (flushed to [`ZondHandler`](crate::ZondHandler)).
+///
+/// Policy is checked on each operation right before it is saved, without holding collection's lock,
+/// so it may call [`CollectionHandle::flush`] of the same collection.
+/// If methods of one collection are called from several threads at once, [`FlushContext`] may be slightly out of date.
+/// One policy instance is shared between all collections constructed with the same [`Zond`](crate::Zond) and its clones,
+/// so collection-specific state is provided by [`FlushContext`] instead of being stored in the policy.
+///
+/// # Example
+/// ```
+/// # use zond::{FlushContext, FlushPolicy, zvec::ZVecOperation};
+/// // Flush operations right after the vector was cleared.
+/// struct OnClear;
+///
+/// impl<T> FlushPolicy<ZVecOperation<T>> for OnClear {
+///     fn should_flush(&self, context: &FlushContext<'_, ZVecOperation<T>>) -> bool {
+///         matches!(context.operation().get_type(), ZVecOperation::Clear { .. })
+///     }
+/// }
+/// ```
+pub trait FlushPolicy<T: OperationType>: Send + Sync {
+    /// Returns `true` if collected operations should be handled now.
+    ///
+    /// It is called on the thread that called collection's method, so it must not block for long.
+    /// It must not panic: panic is propagated to the caller of collection's method and the operation is lost.
+    fn should_flush(&self, context: &FlushContext<'_, T>) -> bool;
+
+    /// Called once when collection is constructed. Default implementation does nothing.
+    ///
+    /// Policies that handle operations not only on method calls, e.g. [`IntervalPolicy`], save `collection` here.
+    fn register(&self, collection: CollectionHandle<T>) {
+        let _ = collection;
+    }
+}
+
+/// Weak handle to collection's collected operations. It doesn't prevent collection from being dropped.
+pub struct CollectionHandle<T: OperationType> {
+    shared: Weak<SharedBuffer<T>>,
+}
+
+impl<T: OperationType> CollectionHandle<T> {
+    pub(crate) fn new(shared: Weak<SharedBuffer<T>>) -> Self {
+        Self { shared }
+    }
+
+    /// Handles collected operations if there are any.
+    ///
+    /// Returns `false` if collection is already dropped.
+    pub fn flush(&self) -> bool {
+        match self.shared.upgrade() {
+            Some(shared) => {
+                shared.handle_non_empty();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T: OperationType> Clone for CollectionHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+/// Collection's state that [`FlushPolicy`] makes decision on.
+pub struct FlushContext<'a, T: OperationType> {
+    buffered_operations: usize,
+    since_last_flush: Duration,
+    operation: &'a Operation<T>,
+}
+
+impl<'a, T: OperationType> FlushContext<'a, T> {
+    /// Constructs `FlushContext`. Collections construct it themselves, so it is useful only to test policies.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::{num::NonZeroUsize, time::Duration};
+    /// # use zond::{FlushContext, FlushPolicy, Operation, Policy, zvec::ZVecOperation};
+    /// let policy = Policy::on_count_operations(NonZeroUsize::new(3).unwrap());
+    /// let operation: Operation<ZVecOperation<usize>> = Operation::new(ZVecOperation::New);
+    /// assert!(policy.should_flush(&FlushContext::new(3, Duration::ZERO, &operation)));
+    /// ```
+    pub fn new(
+        buffered_operations: usize,
+        since_last_flush: Duration,
+        operation: &'a Operation<T>,
+    ) -> Self {
+        Self {
+            buffered_operations,
+            since_last_flush,
+            operation,
+        }
+    }
+
+    /// Count of operations since the last handling including the one being saved, even if some of them were dropped.
+    pub fn buffered_operations(&self) -> usize {
+        self.buffered_operations
+    }
+
+    /// Time since the last handling or since collection's construction if there was no handling yet.
+    pub fn since_last_flush(&self) -> Duration {
+        self.since_last_flush
+    }
+
+    /// The operation that is being saved.
+    pub fn operation(&self) -> &'a Operation<T> {
+        self.operation
+    }
+}
+
+// Rules are stateless: they are checked against collection's `FlushContext`.
+// So the state is reset uniformly for every rule, no matter which one caused handling.
+#[derive(Clone)]
 pub(crate) enum PolicyInner {
     // Operations will be handled only on collection's drop.
     OnDropOnly,
     // Operatons will be handled each N method call.
-    OnCountOperations {
-        max_operations: usize,
-        current_operations: RefCell<usize>,
-    },
+    OnCountOperations { max_operations: usize },
     // Operations will be handled at first method call that happened after given period since last handling.
-    LessOften {
-        duration: Duration,
-        last_collect: RefCell<Instant>,
-    },
+    LessOften { duration: Duration },
+    // Operations will be handled when any of rules says so.
+    AnyOf(Vec<PolicyInner>),
+    // Operations will be handled when all of rules say so.
+    AllOf(Vec<PolicyInner>),
+    // Operations will be handled when rule says they shouldn't.
+    Not(Box<PolicyInner>),
 }
 
-impl Clone for PolicyInner {
-    fn clone(&self) -> Self {
+impl PolicyInner {
+    fn should_flush<T: OperationType>(&self, context: &FlushContext<'_, T>) -> bool {
         match self {
-            Self::OnDropOnly => Self::OnDropOnly,
-            Self::OnCountOperations { max_operations, .. } => Self::OnCountOperations {
-                max_operations: *max_operations,
-                current_operations: RefCell::new(0),
-            },
-            Self::LessOften { duration, .. } => Self::LessOften {
-                duration: *duration,
-                last_collect: RefCell::new(Instant::now()),
-            },
+            Self::OnDropOnly => false,
+            Self::OnCountOperations { max_operations } => {
+                context.buffered_operations() >= *max_operations
+            }
+            Self::LessOften { duration } => context.since_last_flush() > *duration,
+            Self::AnyOf(policies) => policies.iter().any(|policy| policy.should_flush(context)),
+            Self::AllOf(policies) => policies.iter().all(|policy| policy.should_flush(context)),
+            Self::Not(policy) => !policy.should_flush(context),
         }
     }
 }
 
-/// Desribes rules when collected operations will be handled.
+/// Built-in [`FlushPolicy`] implementation.
+///
+/// Rules can be combined with [`any_of`](Policy::any_of), [`all_of`](Policy::all_of) and `!` ([`Not`]).
+/// Whenever operations are handled, all counters and timers of all combined rules start over.
+///
+/// # Example
+/// ```
+/// # use std::{num::NonZeroUsize, time::Duration};
+/// # use zond::Policy;
+/// // Handle operations every 1000 method calls or every 5 seconds, whichever comes first.
+/// let policy = Policy::any_of([
+///     Policy::on_count_operations(NonZeroUsize::new(1000).unwrap()),
+///     Policy::less_often(Duration::from_secs(5)),
+/// ]);
+/// ```
 #[derive(Clone)]
 pub struct Policy {
-    pub(crate) inner: PolicyInner,
+    inner: PolicyInner,
 }
 
 impl Policy {
@@ -56,7 +190,6 @@ impl Policy {
         Self {
             inner: PolicyInner::OnCountOperations {
                 max_operations: max_operations.get(),
-                current_operations: RefCell::new(0),
             },
         }
     }
@@ -64,10 +197,126 @@ impl Policy {
     /// Operations will be handled at first method call that happened after given period since last handling.
     pub fn less_often(duration: Duration) -> Self {
         Self {
-            inner: PolicyInner::LessOften {
-                duration,
-                last_collect: RefCell::new(Instant::now()),
-            },
+            inner: PolicyInner::LessOften { duration },
+        }
+    }
+
+    /// Operations will be handled when any of given policies says so. Empty `policies` never handle operations.
+    pub fn any_of(policies: impl IntoIterator<Item = Policy>) -> Self {
+        Self {
+            inner: PolicyInner::AnyOf(policies.into_iter().map(|policy| policy.inner).collect()),
+        }
+    }
+
+    /// Operations will be handled when all of given policies say so. Empty `policies` handle operations at each method call.
+    pub fn all_of(policies: impl IntoIterator<Item = Policy>) -> Self {
+        Self {
+            inner: PolicyInner::AllOf(policies.into_iter().map(|policy| policy.inner).collect()),
+        }
+    }
+}
+
+impl Not for Policy {
+    type Output = Policy;
+
+    /// Operations will be handled when policy says they shouldn't.
+    fn not(self) -> Self::Output {
+        Self {
+            inner: PolicyInner::Not(Box::new(self.inner)),
+        }
+    }
+}
+
+impl<T: OperationType> FlushPolicy<T> for Policy {
+    fn should_flush(&self, context: &FlushContext<'_, T>) -> bool {
+        self.inner.should_flush(context)
+    }
+}
+
+// Type-erased `CollectionHandle`, so collections with different operation types can be flushed by one thread.
+trait Flush: Send {
+    fn flush(&self) -> bool;
+}
+
+impl<T: OperationType + Send + 'static> Flush for CollectionHandle<T> {
+    fn flush(&self) -> bool {
+        CollectionHandle::flush(self)
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    collections: Vec<Box<dyn Flush>>,
+    worker_running: bool,
+}
+
+/// [`FlushPolicy`] that handles operations from the background thread every `interval`,
+/// even if collection isn't used at all. Operations are also handled whenever inner [`Policy`] says so.
+///
+/// The thread is spawned when first collection is constructed and stops when all collections are dropped.
+/// Clones of `IntervalPolicy` share the same thread.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use zond::{IntervalPolicy, Policy};
+/// // Handle operations every second.
+/// let policy = IntervalPolicy::new(Duration::from_secs(1), Policy::on_drop_only());
+/// ```
+#[derive(Clone)]
+pub struct IntervalPolicy {
+    interval: Duration,
+    policy: Policy,
+    registry: Arc<Mutex<Registry>>,
+}
+
+impl IntervalPolicy {
+    /// Constructs `IntervalPolicy`. Pass [`Policy::on_drop_only`] as `policy` to handle operations only by timer.
+    pub fn new(interval: Duration, policy: Policy) -> Self {
+        Self {
+            interval,
+            policy,
+            registry: Arc::default(),
+        }
+    }
+
+    fn spawn_worker(&self) {
+        let interval = self.interval;
+        let registry = Arc::clone(&self.registry);
+        thread::spawn(move || loop {
+            thread::sleep(interval);
+            // Collections are flushed without holding the lock, so handlers can construct new collections.
+            let collections = std::mem::take(&mut registry.lock().unwrap().collections);
+            let mut alive: Vec<_> = collections
+                .into_iter()
+                .filter(|collection| {
+                    // Handler's panic mustn't stop the thread, otherwise collections would never be flushed by timer again.
+                    // Collection that panicked is kept since it isn't dropped.
+                    panic::catch_unwind(AssertUnwindSafe(|| collection.flush())).unwrap_or(true)
+                })
+                .collect();
+            let mut registry = registry.lock().unwrap();
+            alive.append(&mut registry.collections);
+            registry.collections = alive;
+            if registry.collections.is_empty() {
+                registry.worker_running = false;
+                return;
+            }
+        });
+    }
+}
+
+impl<T: OperationType + Send + 'static> FlushPolicy<T> for IntervalPolicy {
+    fn should_flush(&self, context: &FlushContext<'_, T>) -> bool {
+        self.policy.inner.should_flush(context)
+    }
+
+    fn register(&self, collection: CollectionHandle<T>) {
+        let mut registry = self.registry.lock().unwrap();
+        registry.collections.push(Box::new(collection));
+        if !registry.worker_running {
+            registry.worker_running = true;
+            self.spawn_worker();
         }
     }
 }
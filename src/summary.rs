@@ -0,0 +1,145 @@
+//! Module contains [`ZondSummary`] that is collected instead of operations when [`Zond::with_summary`](crate::Zond::with_summary) is used.
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::OperationType;
+
+/// Aggregated statistics of some numeric argument, e.g. `additional` of [`ZVecOperation::Reserve`](crate::zvec::ZVecOperation::Reserve).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgumentSummary {
+    sum: usize,
+    min: usize,
+    max: usize,
+}
+
+impl ArgumentSummary {
+    fn new(value: usize) -> Self {
+        Self {
+            sum: value,
+            min: value,
+            max: value,
+        }
+    }
+
+    fn add(&mut self, value: usize) {
+        self.sum = self.sum.saturating_add(value);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Get sum of all values. Saturates at [`usize::MAX`].
+    pub fn get_sum(&self) -> usize {
+        self.sum
+    }
+
+    /// Get the smallest value.
+    pub fn get_min(&self) -> usize {
+        self.min
+    }
+
+    /// Get the largest value.
+    pub fn get_max(&self) -> usize {
+        self.max
+    }
+}
+
+/// Aggregated statistics of operations of the same [`kind`](OperationType::kind).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KindSummary {
+    count: usize,
+    arguments: BTreeMap<&'static str, ArgumentSummary>,
+}
+
+impl KindSummary {
+    /// Get count of operations.
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+
+    /// Get statistics of operations' numeric arguments by their names.
+    pub fn get_arguments(&self) -> &BTreeMap<&'static str, ArgumentSummary> {
+        &self.arguments
+    }
+}
+
+/// Aggregated statistics of operations since the last handling.
+///
+/// Memory is allocated only when operation kind or argument name occurs for the first time in collection's lifetime:
+/// after handling the counters are reset, but kinds and arguments are kept.
+#[derive(Clone, Default)]
+pub struct ZondSummary {
+    count: usize,
+    // Kinds with zero count occured only before the last handling.
+    kinds: BTreeMap<&'static str, KindSummary>,
+}
+
+impl ZondSummary {
+    pub(crate) fn add<T: OperationType>(&mut self, operation: &T) {
+        self.count += 1;
+        let kind = self.kinds.entry(operation.kind()).or_default();
+        kind.count += 1;
+        // Arguments left from before the last handling are overwritten.
+        let first = kind.count == 1;
+        operation.visit_arguments(&mut |name, value| {
+            kind.arguments
+                .entry(name)
+                .and_modify(|argument| {
+                    if first {
+                        *argument = ArgumentSummary::new(value);
+                    } else {
+                        argument.add(value);
+                    }
+                })
+                .or_insert_with(|| ArgumentSummary::new(value));
+        });
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.count = 0;
+        self.kinds.values_mut().for_each(|kind| kind.count = 0);
+    }
+
+    /// Get count of all operations.
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+
+    /// Get statistics by operation [`kind`](OperationType::kind) ordered by kind.
+    pub fn get_kinds(&self) -> impl Iterator<Item = (&'static str, &KindSummary)> {
+        self.kinds
+            .iter()
+            .filter(|(_, kind)| kind.count > 0)
+            .map(|(name, kind)| (*name, kind))
+    }
+
+    /// Get statistics of operations of given [`kind`](OperationType::kind) if there were any.
+    pub fn get_kind(&self, kind: &str) -> Option<&KindSummary> {
+        self.kinds.get(kind).filter(|kind| kind.count > 0)
+    }
+}
+
+// Derived implementations would take kinds that occured only before the last handling into account.
+impl fmt::Debug for ZondSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZondSummary")
+            .field("count", &self.count)
+            .field("kinds", &Kinds(self))
+            .finish()
+    }
+}
+
+struct Kinds<'a>(&'a ZondSummary);
+
+impl fmt::Debug for Kinds<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.0.get_kinds()).finish()
+    }
+}
+
+impl PartialEq for ZondSummary {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && self.get_kinds().eq(other.get_kinds())
+    }
+}
+
+impl Eq for ZondSummary {}
@@ -0,0 +1,374 @@
+//! [`BinaryHeap`]'s analogue with collecting statistics and all corresponding types, structs, traits, etc.
+
+use std::{
+    collections::{
+        binary_heap::{self, Drain, Iter},
+        BinaryHeap, TryReserveError,
+    },
+    ops::{Deref, DerefMut},
+};
+
+use crate::{OperationType, Zond, ZondCollection};
+
+/// Describes [`ZBinaryHeap`]'s operation types or, in other words, called methods.
+///
+/// Operations which names start with `PeekMut` happen through the [`PeekMut`] returned by [`ZBinaryHeap::peek_mut`].
+#[derive(Debug, Clone)]
+pub enum ZBinaryHeapOperation<T: Clone> {
+    New,
+    WithCapacity {
+        capacity: usize,
+    },
+    PeekMut,
+    /// Guard was dropped. `modified` shows whether the top element was mutably accessed through it.
+    PeekMutRelease {
+        modified: bool,
+    },
+    PeekMutPop,
+    Pop,
+    Push {
+        item: T,
+    },
+    IntoSortedVec,
+    Append {
+        other: BinaryHeap<T>,
+    },
+    Retain,
+    Iter,
+    Peek,
+    Capacity,
+    ReserveExact {
+        additional: usize,
+    },
+    Reserve {
+        additional: usize,
+    },
+    TryReserveExact {
+        additional: usize,
+    },
+    TryReserve {
+        additional: usize,
+    },
+    ShrinkToFit,
+    ShrinkTo {
+        min_capacity: usize,
+    },
+    IntoVec,
+    Len,
+    IsEmpty,
+    Drain,
+    Clear,
+    IntoBinaryHeap,
+    FromBinaryHeap {
+        from: BinaryHeap<T>,
+    },
+}
+
+impl<T: Clone> OperationType for ZBinaryHeapOperation<T> {
+    type Capture = ();
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::WithCapacity { .. } => "WithCapacity",
+            Self::PeekMut => "PeekMut",
+            Self::PeekMutRelease { .. } => "PeekMutRelease",
+            Self::PeekMutPop => "PeekMutPop",
+            Self::Pop => "Pop",
+            Self::Push { .. } => "Push",
+            Self::IntoSortedVec => "IntoSortedVec",
+            Self::Append { .. } => "Append",
+            Self::Retain => "Retain",
+            Self::Iter => "Iter",
+            Self::Peek => "Peek",
+            Self::Capacity => "Capacity",
+            Self::ReserveExact { .. } => "ReserveExact",
+            Self::Reserve { .. } => "Reserve",
+            Self::TryReserveExact { .. } => "TryReserveExact",
+            Self::TryReserve { .. } => "TryReserve",
+            Self::ShrinkToFit => "ShrinkToFit",
+            Self::ShrinkTo { .. } => "ShrinkTo",
+            Self::IntoVec => "IntoVec",
+            Self::Len => "Len",
+            Self::IsEmpty => "IsEmpty",
+            Self::Drain => "Drain",
+            Self::Clear => "Clear",
+            Self::IntoBinaryHeap => "IntoBinaryHeap",
+            Self::FromBinaryHeap { .. } => "FromBinaryHeap",
+        }
+    }
+
+    fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        match self {
+            Self::WithCapacity { capacity } => visitor("capacity", *capacity),
+            Self::ReserveExact { additional } => visitor("additional", *additional),
+            Self::Reserve { additional } => visitor("additional", *additional),
+            Self::TryReserveExact { additional } => visitor("additional", *additional),
+            Self::TryReserve { additional } => visitor("additional", *additional),
+            Self::ShrinkTo { min_capacity } => visitor("min_capacity", *min_capacity),
+            _ => {}
+        }
+    }
+}
+
+/// `ZBinaryHeap` is a wrapper around [`BinaryHeap`] providing collecting statistics about operations.
+pub struct ZBinaryHeap<T: Clone> {
+    inner: BinaryHeap<T>,
+    zond_collection: ZondCollection<ZBinaryHeapOperation<T>>,
+}
+
+impl<T: Clone + Ord> ZBinaryHeap<T> {
+    /// Creates `ZBinaryHeap` from existing `BinaryHeap` instance.
+    #[track_caller]
+    pub fn from_binary_heap(from: BinaryHeap<T>, zond: Zond<ZBinaryHeapOperation<T>>) -> Self {
+        let zbinaryheap = Self {
+            inner: from,
+            zond_collection: ZondCollection::new(zond),
+        };
+        zbinaryheap
+            .zond_collection
+            .push_operation(ZBinaryHeapOperation::FromBinaryHeap {
+                from: zbinaryheap.inner.clone(),
+            });
+        zbinaryheap
+    }
+
+    #[track_caller]
+    pub fn new(zond: Zond<ZBinaryHeapOperation<T>>) -> Self {
+        let zbinaryheap = Self {
+            inner: BinaryHeap::new(),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zbinaryheap
+            .zond_collection
+            .push_operation(ZBinaryHeapOperation::New);
+        zbinaryheap
+    }
+
+    #[track_caller]
+    pub fn with_capacity(capacity: usize, zond: Zond<ZBinaryHeapOperation<T>>) -> Self {
+        let zbinaryheap = Self {
+            inner: BinaryHeap::with_capacity(capacity),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zbinaryheap
+            .zond_collection
+            .push_operation(ZBinaryHeapOperation::WithCapacity { capacity });
+        zbinaryheap
+    }
+
+    #[track_caller]
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::PeekMut);
+        self.inner.peek_mut().map(|inner| PeekMut {
+            inner: Some(inner),
+            zond_collection: &self.zond_collection,
+            modified: false,
+        })
+    }
+
+    #[track_caller]
+    pub fn pop(&mut self) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Pop);
+        self.inner.pop()
+    }
+
+    #[track_caller]
+    pub fn push(&mut self, item: T) {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Push { item: item.clone() });
+        self.inner.push(item)
+    }
+
+    #[track_caller]
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let ZBinaryHeap {
+            inner,
+            zond_collection,
+        } = self;
+        zond_collection.push_operation(ZBinaryHeapOperation::IntoSortedVec);
+        inner.into_sorted_vec()
+    }
+
+    #[track_caller]
+    pub fn append(&mut self, other: &mut BinaryHeap<T>) {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Append {
+                other: other.clone(),
+            });
+        self.inner.append(other)
+    }
+
+    #[track_caller]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Retain);
+        self.inner.retain(f)
+    }
+}
+
+impl<T: Clone> ZBinaryHeap<T> {
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Iter);
+        self.inner.iter()
+    }
+
+    #[track_caller]
+    pub fn peek(&self) -> Option<&T> {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Peek);
+        self.inner.peek()
+    }
+
+    #[track_caller]
+    pub fn capacity(&self) -> usize {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Capacity);
+        self.inner.capacity()
+    }
+
+    #[track_caller]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::ReserveExact { additional });
+        self.inner.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Reserve { additional });
+        self.inner.reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::TryReserveExact { additional });
+        self.inner.try_reserve_exact(additional)
+    }
+
+    #[track_caller]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::TryReserve { additional });
+        self.inner.try_reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::ShrinkToFit);
+        self.inner.shrink_to_fit()
+    }
+
+    #[track_caller]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::ShrinkTo { min_capacity });
+        self.inner.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    pub fn into_vec(self) -> Vec<T> {
+        let ZBinaryHeap {
+            inner,
+            zond_collection,
+        } = self;
+        zond_collection.push_operation(ZBinaryHeapOperation::IntoVec);
+        inner.into_vec()
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Len);
+        self.inner.len()
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::IsEmpty);
+        self.inner.is_empty()
+    }
+
+    #[track_caller]
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Drain);
+        self.inner.drain()
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.zond_collection
+            .push_operation(ZBinaryHeapOperation::Clear);
+        self.inner.clear()
+    }
+}
+
+impl<T: Clone> From<ZBinaryHeap<T>> for BinaryHeap<T> {
+    #[track_caller]
+    fn from(zbinaryheap: ZBinaryHeap<T>) -> BinaryHeap<T> {
+        let ZBinaryHeap {
+            inner,
+            zond_collection,
+        } = zbinaryheap;
+        zond_collection.push_operation(ZBinaryHeapOperation::IntoBinaryHeap);
+        inner
+    }
+}
+
+/// Structure wrapping a mutable reference to the greatest item on a [`ZBinaryHeap`].
+///
+/// This is a wrapper around [`binary_heap::PeekMut`] that saves whether the top element was modified or popped through it.
+pub struct PeekMut<'a, T: Clone + Ord> {
+    // `None` only after the guard was consumed by `PeekMut::pop`.
+    inner: Option<binary_heap::PeekMut<'a, T>>,
+    zond_collection: &'a ZondCollection<ZBinaryHeapOperation<T>>,
+    modified: bool,
+}
+
+impl<T: Clone + Ord> PeekMut<'_, T> {
+    /// Removes the peeked value from the heap and returns it.
+    #[track_caller]
+    pub fn pop(mut this: Self) -> T {
+        this.zond_collection
+            .push_operation(ZBinaryHeapOperation::PeekMutPop);
+        binary_heap::PeekMut::pop(this.inner.take().unwrap())
+    }
+}
+
+impl<T: Clone + Ord> Deref for PeekMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().unwrap()
+    }
+}
+
+impl<T: Clone + Ord> DerefMut for PeekMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.modified = true;
+        self.inner.as_mut().unwrap()
+    }
+}
+
+impl<T: Clone + Ord> Drop for PeekMut<'_, T> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            self.zond_collection
+                .push_operation(ZBinaryHeapOperation::PeekMutRelease {
+                    modified: self.modified,
+                });
+        }
+    }
+}
@@ -0,0 +1,420 @@
+//! [`BTreeMap`]'s analogue with collecting statistics and all corresponding types, structs, traits, etc.
+
+use std::{
+    borrow::Borrow,
+    collections::{
+        btree_map::{
+            IntoKeys, IntoValues, Iter, IterMut, Keys, Range, RangeMut, Values, ValuesMut,
+        },
+        BTreeMap,
+    },
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{OperationType, Zond, ZondCollection};
+
+/// Describes [`ZBTreeMap`]'s operation types or, in other words, called methods.
+#[derive(Debug, Clone)]
+pub enum ZBTreeMapOperation<K: Clone, V: Clone> {
+    New,
+    Clear,
+    Get {
+        key: K,
+    },
+    GetKeyValue {
+        key: K,
+    },
+    FirstKeyValue,
+    PopFirst,
+    LastKeyValue,
+    PopLast,
+    ContainsKey {
+        key: K,
+    },
+    GetMut {
+        key: K,
+    },
+    Insert {
+        key: K,
+        value: V,
+    },
+    Remove {
+        key: K,
+    },
+    RemoveEntry {
+        key: K,
+    },
+    Retain,
+    Append {
+        other: BTreeMap<K, V>,
+    },
+    Range {
+        start_bound: Bound<K>,
+        end_bound: Bound<K>,
+    },
+    RangeMut {
+        start_bound: Bound<K>,
+        end_bound: Bound<K>,
+    },
+    SplitOff {
+        key: K,
+    },
+    Iter,
+    IterMut,
+    Keys,
+    Values,
+    ValuesMut,
+    IntoKeys,
+    IntoValues,
+    Len,
+    IsEmpty,
+    IntoBTreeMap,
+    FromBTreeMap {
+        from: BTreeMap<K, V>,
+    },
+}
+
+impl<K: Clone, V: Clone> OperationType for ZBTreeMapOperation<K, V> {
+    type Capture = ();
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::Clear => "Clear",
+            Self::Get { .. } => "Get",
+            Self::GetKeyValue { .. } => "GetKeyValue",
+            Self::FirstKeyValue => "FirstKeyValue",
+            Self::PopFirst => "PopFirst",
+            Self::LastKeyValue => "LastKeyValue",
+            Self::PopLast => "PopLast",
+            Self::ContainsKey { .. } => "ContainsKey",
+            Self::GetMut { .. } => "GetMut",
+            Self::Insert { .. } => "Insert",
+            Self::Remove { .. } => "Remove",
+            Self::RemoveEntry { .. } => "RemoveEntry",
+            Self::Retain => "Retain",
+            Self::Append { .. } => "Append",
+            Self::Range { .. } => "Range",
+            Self::RangeMut { .. } => "RangeMut",
+            Self::SplitOff { .. } => "SplitOff",
+            Self::Iter => "Iter",
+            Self::IterMut => "IterMut",
+            Self::Keys => "Keys",
+            Self::Values => "Values",
+            Self::ValuesMut => "ValuesMut",
+            Self::IntoKeys => "IntoKeys",
+            Self::IntoValues => "IntoValues",
+            Self::Len => "Len",
+            Self::IsEmpty => "IsEmpty",
+            Self::IntoBTreeMap => "IntoBTreeMap",
+            Self::FromBTreeMap { .. } => "FromBTreeMap",
+        }
+    }
+}
+
+/// `ZBTreeMap` is a wrapper around [`BTreeMap`] providing collecting statistics about operations.
+///
+/// Methods that look up by borrowed key, like [`get`](ZBTreeMap::get) or [`range`](ZBTreeMap::range), require the borrowed
+/// form to be convertible back to `K` via [`ToOwned`] so that the key can be saved in operation.
+pub struct ZBTreeMap<K: Clone, V: Clone> {
+    inner: BTreeMap<K, V>,
+    zond_collection: ZondCollection<ZBTreeMapOperation<K, V>>,
+}
+
+impl<K: Clone, V: Clone> ZBTreeMap<K, V> {
+    /// Creates `ZBTreeMap` from existing `BTreeMap` instance.
+    #[track_caller]
+    pub fn from_btree_map(from: BTreeMap<K, V>, zond: Zond<ZBTreeMapOperation<K, V>>) -> Self {
+        let zbtreemap = Self {
+            inner: from,
+            zond_collection: ZondCollection::new(zond),
+        };
+        zbtreemap
+            .zond_collection
+            .push_operation(ZBTreeMapOperation::FromBTreeMap {
+                from: zbtreemap.inner.clone(),
+            });
+        zbtreemap
+    }
+
+    #[track_caller]
+    pub fn new(zond: Zond<ZBTreeMapOperation<K, V>>) -> Self {
+        let zbtreemap = Self {
+            inner: BTreeMap::new(),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zbtreemap
+            .zond_collection
+            .push_operation(ZBTreeMapOperation::New);
+        zbtreemap
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Clear);
+        self.inner.clear()
+    }
+
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Iter);
+        self.inner.iter()
+    }
+
+    #[track_caller]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::IterMut);
+        self.inner.iter_mut()
+    }
+
+    #[track_caller]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Keys);
+        self.inner.keys()
+    }
+
+    #[track_caller]
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Values);
+        self.inner.values()
+    }
+
+    #[track_caller]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::ValuesMut);
+        self.inner.values_mut()
+    }
+
+    #[track_caller]
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        let ZBTreeMap {
+            inner,
+            zond_collection,
+        } = self;
+        zond_collection.push_operation(ZBTreeMapOperation::IntoKeys);
+        inner.into_keys()
+    }
+
+    #[track_caller]
+    pub fn into_values(self) -> IntoValues<K, V> {
+        let ZBTreeMap {
+            inner,
+            zond_collection,
+        } = self;
+        zond_collection.push_operation(ZBTreeMapOperation::IntoValues);
+        inner.into_values()
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.zond_collection.push_operation(ZBTreeMapOperation::Len);
+        self.inner.len()
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::IsEmpty);
+        self.inner.is_empty()
+    }
+}
+
+impl<K, V> ZBTreeMap<K, V>
+where
+    K: Clone + Ord,
+    V: Clone,
+{
+    #[track_caller]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Get {
+                key: key.to_owned(),
+            });
+        self.inner.get(key)
+    }
+
+    #[track_caller]
+    pub fn get_key_value<Q>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::GetKeyValue { key: k.to_owned() });
+        self.inner.get_key_value(k)
+    }
+
+    #[track_caller]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::FirstKeyValue);
+        self.inner.first_key_value()
+    }
+
+    #[track_caller]
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::PopFirst);
+        self.inner.pop_first()
+    }
+
+    #[track_caller]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::LastKeyValue);
+        self.inner.last_key_value()
+    }
+
+    #[track_caller]
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::PopLast);
+        self.inner.pop_last()
+    }
+
+    #[track_caller]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::ContainsKey {
+                key: key.to_owned(),
+            });
+        self.inner.contains_key(key)
+    }
+
+    #[track_caller]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::GetMut {
+                key: key.to_owned(),
+            });
+        self.inner.get_mut(key)
+    }
+
+    #[track_caller]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Insert {
+                key: key.clone(),
+                value: value.clone(),
+            });
+        self.inner.insert(key, value)
+    }
+
+    #[track_caller]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Remove {
+                key: key.to_owned(),
+            });
+        self.inner.remove(key)
+    }
+
+    #[track_caller]
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::RemoveEntry {
+                key: key.to_owned(),
+            });
+        self.inner.remove_entry(key)
+    }
+
+    #[track_caller]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Retain);
+        self.inner.retain(f)
+    }
+
+    #[track_caller]
+    pub fn append(&mut self, other: &mut BTreeMap<K, V>) {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Append {
+                other: other.clone(),
+            });
+        self.inner.append(other)
+    }
+
+    #[track_caller]
+    pub fn range<T, R>(&self, range: R) -> Range<'_, K, V>
+    where
+        T: Ord + ToOwned<Owned = K> + ?Sized,
+        K: Borrow<T>,
+        R: RangeBounds<T>,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::Range {
+                start_bound: range.start_bound().map(ToOwned::to_owned),
+                end_bound: range.end_bound().map(ToOwned::to_owned),
+            });
+        self.inner.range(range)
+    }
+
+    #[track_caller]
+    pub fn range_mut<T, R>(&mut self, range: R) -> RangeMut<'_, K, V>
+    where
+        T: Ord + ToOwned<Owned = K> + ?Sized,
+        K: Borrow<T>,
+        R: RangeBounds<T>,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::RangeMut {
+                start_bound: range.start_bound().map(ToOwned::to_owned),
+                end_bound: range.end_bound().map(ToOwned::to_owned),
+            });
+        self.inner.range_mut(range)
+    }
+
+    #[track_caller]
+    pub fn split_off<Q>(&mut self, key: &Q) -> BTreeMap<K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeMapOperation::SplitOff {
+                key: key.to_owned(),
+            });
+        self.inner.split_off(key)
+    }
+}
+
+impl<K: Clone, V: Clone> From<ZBTreeMap<K, V>> for BTreeMap<K, V> {
+    #[track_caller]
+    fn from(zbtreemap: ZBTreeMap<K, V>) -> BTreeMap<K, V> {
+        let ZBTreeMap {
+            inner,
+            zond_collection,
+        } = zbtreemap;
+        zond_collection.push_operation(ZBTreeMapOperation::IntoBTreeMap);
+        inner
+    }
+}
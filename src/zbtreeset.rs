@@ -0,0 +1,404 @@
+//! [`BTreeSet`]'s analogue with collecting statistics and all corresponding types, structs, traits, etc.
+
+use std::{
+    borrow::Borrow,
+    collections::{
+        btree_set::{Difference, Intersection, Iter, Range, SymmetricDifference, Union},
+        BTreeSet,
+    },
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{OperationType, Zond, ZondCollection};
+
+/// Describes [`ZBTreeSet`]'s operation types or, in other words, called methods.
+///
+/// `present` field shows whether the value was in the set before the operation.
+#[derive(Debug, Clone)]
+pub enum ZBTreeSetOperation<T: Clone> {
+    New,
+    Range {
+        start_bound: Bound<T>,
+        end_bound: Bound<T>,
+    },
+    Difference,
+    SymmetricDifference,
+    Intersection,
+    Union,
+    Clear,
+    Contains {
+        value: T,
+        present: bool,
+    },
+    Get {
+        value: T,
+        present: bool,
+    },
+    IsDisjoint,
+    IsSubset,
+    IsSuperset,
+    First,
+    Last,
+    PopFirst,
+    PopLast,
+    Insert {
+        value: T,
+        present: bool,
+    },
+    Replace {
+        value: T,
+        present: bool,
+    },
+    Remove {
+        value: T,
+        present: bool,
+    },
+    Take {
+        value: T,
+        present: bool,
+    },
+    Retain,
+    Append {
+        other: BTreeSet<T>,
+    },
+    SplitOff {
+        value: T,
+    },
+    Iter,
+    Len,
+    IsEmpty,
+    IntoBTreeSet,
+    FromBTreeSet {
+        from: BTreeSet<T>,
+    },
+}
+
+impl<T: Clone> OperationType for ZBTreeSetOperation<T> {
+    type Capture = ();
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::Range { .. } => "Range",
+            Self::Difference => "Difference",
+            Self::SymmetricDifference => "SymmetricDifference",
+            Self::Intersection => "Intersection",
+            Self::Union => "Union",
+            Self::Clear => "Clear",
+            Self::Contains { .. } => "Contains",
+            Self::Get { .. } => "Get",
+            Self::IsDisjoint => "IsDisjoint",
+            Self::IsSubset => "IsSubset",
+            Self::IsSuperset => "IsSuperset",
+            Self::First => "First",
+            Self::Last => "Last",
+            Self::PopFirst => "PopFirst",
+            Self::PopLast => "PopLast",
+            Self::Insert { .. } => "Insert",
+            Self::Replace { .. } => "Replace",
+            Self::Remove { .. } => "Remove",
+            Self::Take { .. } => "Take",
+            Self::Retain => "Retain",
+            Self::Append { .. } => "Append",
+            Self::SplitOff { .. } => "SplitOff",
+            Self::Iter => "Iter",
+            Self::Len => "Len",
+            Self::IsEmpty => "IsEmpty",
+            Self::IntoBTreeSet => "IntoBTreeSet",
+            Self::FromBTreeSet { .. } => "FromBTreeSet",
+        }
+    }
+}
+
+/// `ZBTreeSet` is a wrapper around [`BTreeSet`] providing collecting statistics about operations.
+///
+/// Methods that look up by borrowed value, like [`contains`](ZBTreeSet::contains) or [`range`](ZBTreeSet::range),
+/// require the borrowed form to be convertible back to `T` via [`ToOwned`] so that the value can be saved in operation.
+pub struct ZBTreeSet<T: Clone> {
+    inner: BTreeSet<T>,
+    zond_collection: ZondCollection<ZBTreeSetOperation<T>>,
+}
+
+impl<T: Clone> ZBTreeSet<T> {
+    /// Creates `ZBTreeSet` from existing `BTreeSet` instance.
+    #[track_caller]
+    pub fn from_btree_set(from: BTreeSet<T>, zond: Zond<ZBTreeSetOperation<T>>) -> Self {
+        let zbtreeset = Self {
+            inner: from,
+            zond_collection: ZondCollection::new(zond),
+        };
+        zbtreeset
+            .zond_collection
+            .push_operation(ZBTreeSetOperation::FromBTreeSet {
+                from: zbtreeset.inner.clone(),
+            });
+        zbtreeset
+    }
+
+    #[track_caller]
+    pub fn new(zond: Zond<ZBTreeSetOperation<T>>) -> Self {
+        let zbtreeset = Self {
+            inner: BTreeSet::new(),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zbtreeset
+            .zond_collection
+            .push_operation(ZBTreeSetOperation::New);
+        zbtreeset
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Clear);
+        self.inner.clear()
+    }
+
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Iter);
+        self.inner.iter()
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.zond_collection.push_operation(ZBTreeSetOperation::Len);
+        self.inner.len()
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::IsEmpty);
+        self.inner.is_empty()
+    }
+}
+
+impl<T> ZBTreeSet<T>
+where
+    T: Clone + Ord,
+{
+    #[track_caller]
+    pub fn range<K, R>(&self, range: R) -> Range<'_, T>
+    where
+        K: Ord + ToOwned<Owned = T> + ?Sized,
+        T: Borrow<K>,
+        R: RangeBounds<K>,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Range {
+                start_bound: range.start_bound().map(ToOwned::to_owned),
+                end_bound: range.end_bound().map(ToOwned::to_owned),
+            });
+        self.inner.range(range)
+    }
+
+    #[track_caller]
+    pub fn difference<'a>(&'a self, other: &'a BTreeSet<T>) -> Difference<'a, T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Difference);
+        self.inner.difference(other)
+    }
+
+    #[track_caller]
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a BTreeSet<T>,
+    ) -> SymmetricDifference<'a, T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::SymmetricDifference);
+        self.inner.symmetric_difference(other)
+    }
+
+    #[track_caller]
+    pub fn intersection<'a>(&'a self, other: &'a BTreeSet<T>) -> Intersection<'a, T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Intersection);
+        self.inner.intersection(other)
+    }
+
+    #[track_caller]
+    pub fn union<'a>(&'a self, other: &'a BTreeSet<T>) -> Union<'a, T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Union);
+        self.inner.union(other)
+    }
+
+    #[track_caller]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = T> + ?Sized,
+    {
+        let present = self.inner.contains(value);
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Contains {
+                value: value.to_owned(),
+                present,
+            });
+        present
+    }
+
+    #[track_caller]
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = T> + ?Sized,
+    {
+        let got = self.inner.get(value);
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Get {
+                value: value.to_owned(),
+                present: got.is_some(),
+            });
+        got
+    }
+
+    #[track_caller]
+    pub fn is_disjoint(&self, other: &BTreeSet<T>) -> bool {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::IsDisjoint);
+        self.inner.is_disjoint(other)
+    }
+
+    #[track_caller]
+    pub fn is_subset(&self, other: &BTreeSet<T>) -> bool {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::IsSubset);
+        self.inner.is_subset(other)
+    }
+
+    #[track_caller]
+    pub fn is_superset(&self, other: &BTreeSet<T>) -> bool {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::IsSuperset);
+        self.inner.is_superset(other)
+    }
+
+    #[track_caller]
+    pub fn first(&self) -> Option<&T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::First);
+        self.inner.first()
+    }
+
+    #[track_caller]
+    pub fn last(&self) -> Option<&T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Last);
+        self.inner.last()
+    }
+
+    #[track_caller]
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::PopFirst);
+        self.inner.pop_first()
+    }
+
+    #[track_caller]
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::PopLast);
+        self.inner.pop_last()
+    }
+
+    #[track_caller]
+    pub fn insert(&mut self, value: T) -> bool {
+        let operation_value = value.clone();
+        let inserted = self.inner.insert(value);
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Insert {
+                value: operation_value,
+                present: !inserted,
+            });
+        inserted
+    }
+
+    #[track_caller]
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        let operation_value = value.clone();
+        let replaced = self.inner.replace(value);
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Replace {
+                value: operation_value,
+                present: replaced.is_some(),
+            });
+        replaced
+    }
+
+    #[track_caller]
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = T> + ?Sized,
+    {
+        let present = self.inner.remove(value);
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Remove {
+                value: value.to_owned(),
+                present,
+            });
+        present
+    }
+
+    #[track_caller]
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = T> + ?Sized,
+    {
+        let taken = self.inner.take(value);
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Take {
+                value: value.to_owned(),
+                present: taken.is_some(),
+            });
+        taken
+    }
+
+    #[track_caller]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Retain);
+        self.inner.retain(f)
+    }
+
+    #[track_caller]
+    pub fn append(&mut self, other: &mut BTreeSet<T>) {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::Append {
+                other: other.clone(),
+            });
+        self.inner.append(other)
+    }
+
+    #[track_caller]
+    pub fn split_off<Q>(&mut self, value: &Q) -> BTreeSet<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = T> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZBTreeSetOperation::SplitOff {
+                value: value.to_owned(),
+            });
+        self.inner.split_off(value)
+    }
+}
+
+impl<T: Clone> From<ZBTreeSet<T>> for BTreeSet<T> {
+    #[track_caller]
+    fn from(zbtreeset: ZBTreeSet<T>) -> BTreeSet<T> {
+        let ZBTreeSet {
+            inner,
+            zond_collection,
+        } = zbtreeset;
+        zond_collection.push_operation(ZBTreeSetOperation::IntoBTreeSet);
+        inner
+    }
+}
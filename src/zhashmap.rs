@@ -0,0 +1,516 @@
+//! [`HashMap`]'s analogue with collecting statistics and all corresponding types, structs, traits, etc.
+
+use std::{
+    borrow::Borrow,
+    collections::{
+        hash_map::{self, Drain, Iter, IterMut, Keys, RandomState, Values, ValuesMut},
+        HashMap, TryReserveError,
+    },
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{OperationType, Zond, ZondCollection};
+
+/// State of the [`HashMap`]'s entry at the moment when operation with it happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryState {
+    Occupied,
+    Vacant,
+}
+
+impl<K, V> From<&hash_map::Entry<'_, K, V>> for EntryState {
+    fn from(entry: &hash_map::Entry<'_, K, V>) -> Self {
+        match entry {
+            hash_map::Entry::Occupied(_) => Self::Occupied,
+            hash_map::Entry::Vacant(_) => Self::Vacant,
+        }
+    }
+}
+
+/// Describes [`ZHashMap`]'s operation types or, in other words, called methods.
+///
+/// Operations which names start with `Entry` are called through the [`Entry`] returned by [`ZHashMap::entry`].
+#[derive(Debug, Clone)]
+pub enum ZHashMapOperation<K: Clone, V: Clone> {
+    New,
+    WithCapacity { capacity: usize },
+    WithHasher,
+    WithCapacityAndHasher { capacity: usize },
+    Capacity,
+    Keys,
+    Values,
+    ValuesMut,
+    Iter,
+    IterMut,
+    Len,
+    IsEmpty,
+    Drain,
+    Retain,
+    Clear,
+    Hasher,
+    Reserve { additional: usize },
+    TryReserve { additional: usize },
+    ShrinkToFit,
+    ShrinkTo { min_capacity: usize },
+    Entry { key: K, state: EntryState },
+    EntryKey { state: EntryState },
+    EntryOrInsert { state: EntryState, default: V },
+    EntryOrInsertWith { state: EntryState },
+    EntryOrInsertWithKey { state: EntryState },
+    EntryOrDefault { state: EntryState },
+    EntryAndModify { state: EntryState },
+    Get { key: K },
+    GetKeyValue { key: K },
+    ContainsKey { key: K },
+    GetMut { key: K },
+    Insert { key: K, value: V },
+    Remove { key: K },
+    RemoveEntry { key: K },
+    IntoHashMap,
+    FromHashMap { from: Vec<(K, V)> },
+}
+
+impl<K: Clone, V: Clone> OperationType for ZHashMapOperation<K, V> {
+    type Capture = ();
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::WithCapacity { .. } => "WithCapacity",
+            Self::WithHasher => "WithHasher",
+            Self::WithCapacityAndHasher { .. } => "WithCapacityAndHasher",
+            Self::Capacity => "Capacity",
+            Self::Keys => "Keys",
+            Self::Values => "Values",
+            Self::ValuesMut => "ValuesMut",
+            Self::Iter => "Iter",
+            Self::IterMut => "IterMut",
+            Self::Len => "Len",
+            Self::IsEmpty => "IsEmpty",
+            Self::Drain => "Drain",
+            Self::Retain => "Retain",
+            Self::Clear => "Clear",
+            Self::Hasher => "Hasher",
+            Self::Reserve { .. } => "Reserve",
+            Self::TryReserve { .. } => "TryReserve",
+            Self::ShrinkToFit => "ShrinkToFit",
+            Self::ShrinkTo { .. } => "ShrinkTo",
+            Self::Entry { .. } => "Entry",
+            Self::EntryKey { .. } => "EntryKey",
+            Self::EntryOrInsert { .. } => "EntryOrInsert",
+            Self::EntryOrInsertWith { .. } => "EntryOrInsertWith",
+            Self::EntryOrInsertWithKey { .. } => "EntryOrInsertWithKey",
+            Self::EntryOrDefault { .. } => "EntryOrDefault",
+            Self::EntryAndModify { .. } => "EntryAndModify",
+            Self::Get { .. } => "Get",
+            Self::GetKeyValue { .. } => "GetKeyValue",
+            Self::ContainsKey { .. } => "ContainsKey",
+            Self::GetMut { .. } => "GetMut",
+            Self::Insert { .. } => "Insert",
+            Self::Remove { .. } => "Remove",
+            Self::RemoveEntry { .. } => "RemoveEntry",
+            Self::IntoHashMap => "IntoHashMap",
+            Self::FromHashMap { .. } => "FromHashMap",
+        }
+    }
+
+    fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        match self {
+            Self::WithCapacity { capacity } => visitor("capacity", *capacity),
+            Self::WithCapacityAndHasher { capacity } => visitor("capacity", *capacity),
+            Self::Reserve { additional } => visitor("additional", *additional),
+            Self::TryReserve { additional } => visitor("additional", *additional),
+            Self::ShrinkTo { min_capacity } => visitor("min_capacity", *min_capacity),
+            _ => {}
+        }
+    }
+}
+
+/// `ZHashMap` is a wrapper around [`HashMap`] providing collecting statistics about operations.
+///
+/// Methods that look up by borrowed key, like [`get`](ZHashMap::get), require the borrowed form to be convertible back
+/// to `K` via [`ToOwned`] so that the key can be saved in operation.
+pub struct ZHashMap<K: Clone, V: Clone, S = RandomState> {
+    inner: HashMap<K, V, S>,
+    zond_collection: ZondCollection<ZHashMapOperation<K, V>>,
+}
+
+impl<K: Clone, V: Clone> ZHashMap<K, V, RandomState> {
+    #[track_caller]
+    pub fn new(zond: Zond<ZHashMapOperation<K, V>>) -> Self {
+        let zhashmap = Self {
+            inner: HashMap::new(),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashmap
+            .zond_collection
+            .push_operation(ZHashMapOperation::New);
+        zhashmap
+    }
+
+    #[track_caller]
+    pub fn with_capacity(capacity: usize, zond: Zond<ZHashMapOperation<K, V>>) -> Self {
+        let zhashmap = Self {
+            inner: HashMap::with_capacity(capacity),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashmap
+            .zond_collection
+            .push_operation(ZHashMapOperation::WithCapacity { capacity });
+        zhashmap
+    }
+}
+
+impl<K: Clone, V: Clone, S> ZHashMap<K, V, S> {
+    /// Creates `ZHashMap` from existing `HashMap` instance.
+    #[track_caller]
+    pub fn from_hash_map(from: HashMap<K, V, S>, zond: Zond<ZHashMapOperation<K, V>>) -> Self {
+        let zhashmap = Self {
+            inner: from,
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashmap
+            .zond_collection
+            .push_operation(ZHashMapOperation::FromHashMap {
+                from: zhashmap
+                    .inner
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+            });
+        zhashmap
+    }
+
+    #[track_caller]
+    pub fn with_hasher(hash_builder: S, zond: Zond<ZHashMapOperation<K, V>>) -> Self {
+        let zhashmap = Self {
+            inner: HashMap::with_hasher(hash_builder),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashmap
+            .zond_collection
+            .push_operation(ZHashMapOperation::WithHasher);
+        zhashmap
+    }
+
+    #[track_caller]
+    pub fn with_capacity_and_hasher(
+        capacity: usize,
+        hash_builder: S,
+        zond: Zond<ZHashMapOperation<K, V>>,
+    ) -> Self {
+        let zhashmap = Self {
+            inner: HashMap::with_capacity_and_hasher(capacity, hash_builder),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashmap
+            .zond_collection
+            .push_operation(ZHashMapOperation::WithCapacityAndHasher { capacity });
+        zhashmap
+    }
+
+    #[track_caller]
+    pub fn capacity(&self) -> usize {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Capacity);
+        self.inner.capacity()
+    }
+
+    #[track_caller]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.zond_collection.push_operation(ZHashMapOperation::Keys);
+        self.inner.keys()
+    }
+
+    #[track_caller]
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Values);
+        self.inner.values()
+    }
+
+    #[track_caller]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::ValuesMut);
+        self.inner.values_mut()
+    }
+
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.zond_collection.push_operation(ZHashMapOperation::Iter);
+        self.inner.iter()
+    }
+
+    #[track_caller]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::IterMut);
+        self.inner.iter_mut()
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.zond_collection.push_operation(ZHashMapOperation::Len);
+        self.inner.len()
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::IsEmpty);
+        self.inner.is_empty()
+    }
+
+    #[track_caller]
+    pub fn drain(&mut self) -> Drain<'_, K, V> {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Drain);
+        self.inner.drain()
+    }
+
+    #[track_caller]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Retain);
+        self.inner.retain(f)
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Clear);
+        self.inner.clear()
+    }
+
+    #[track_caller]
+    pub fn hasher(&self) -> &S {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Hasher);
+        self.inner.hasher()
+    }
+}
+
+impl<K, V, S> ZHashMap<K, V, S>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    S: BuildHasher,
+{
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Reserve { additional });
+        self.inner.reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::TryReserve { additional });
+        self.inner.try_reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::ShrinkToFit);
+        self.inner.shrink_to_fit()
+    }
+
+    #[track_caller]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::ShrinkTo { min_capacity });
+        self.inner.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let key_clone = key.clone();
+        let inner = self.inner.entry(key);
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Entry {
+                key: key_clone,
+                state: EntryState::from(&inner),
+            });
+        Entry {
+            inner,
+            zond_collection: &self.zond_collection,
+        }
+    }
+
+    #[track_caller]
+    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Get { key: k.to_owned() });
+        self.inner.get(k)
+    }
+
+    #[track_caller]
+    pub fn get_key_value<Q>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::GetKeyValue { key: k.to_owned() });
+        self.inner.get_key_value(k)
+    }
+
+    #[track_caller]
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::ContainsKey { key: k.to_owned() });
+        self.inner.contains_key(k)
+    }
+
+    #[track_caller]
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::GetMut { key: k.to_owned() });
+        self.inner.get_mut(k)
+    }
+
+    #[track_caller]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Insert {
+                key: k.clone(),
+                value: v.clone(),
+            });
+        self.inner.insert(k, v)
+    }
+
+    #[track_caller]
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::Remove { key: k.to_owned() });
+        self.inner.remove(k)
+    }
+
+    #[track_caller]
+    pub fn remove_entry<Q>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = K> + ?Sized,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::RemoveEntry { key: k.to_owned() });
+        self.inner.remove_entry(k)
+    }
+}
+
+impl<K: Clone, V: Clone, S> From<ZHashMap<K, V, S>> for HashMap<K, V, S> {
+    #[track_caller]
+    fn from(zhashmap: ZHashMap<K, V, S>) -> HashMap<K, V, S> {
+        let ZHashMap {
+            inner,
+            zond_collection,
+        } = zhashmap;
+        zond_collection.push_operation(ZHashMapOperation::IntoHashMap);
+        inner
+    }
+}
+
+/// A view into a single entry in a [`ZHashMap`], which may either be vacant or occupied.
+///
+/// This is a wrapper around [`hash_map::Entry`] that saves which path was taken while working with entry.
+pub struct Entry<'a, K: Clone, V: Clone> {
+    inner: hash_map::Entry<'a, K, V>,
+    zond_collection: &'a ZondCollection<ZHashMapOperation<K, V>>,
+}
+
+impl<'a, K: Clone, V: Clone> Entry<'a, K, V> {
+    fn state(&self) -> EntryState {
+        EntryState::from(&self.inner)
+    }
+
+    #[track_caller]
+    pub fn key(&self) -> &K {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::EntryKey {
+                state: self.state(),
+            });
+        self.inner.key()
+    }
+
+    #[track_caller]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::EntryOrInsert {
+                state: self.state(),
+                default: default.clone(),
+            });
+        self.inner.or_insert(default)
+    }
+
+    #[track_caller]
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::EntryOrInsertWith {
+                state: self.state(),
+            });
+        self.inner.or_insert_with(default)
+    }
+
+    #[track_caller]
+    pub fn or_insert_with_key<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce(&K) -> V,
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::EntryOrInsertWithKey {
+                state: self.state(),
+            });
+        self.inner.or_insert_with_key(default)
+    }
+
+    #[track_caller]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::EntryAndModify {
+                state: self.state(),
+            });
+        Self {
+            inner: self.inner.and_modify(f),
+            zond_collection: self.zond_collection,
+        }
+    }
+}
+
+impl<'a, K: Clone, V: Clone + Default> Entry<'a, K, V> {
+    #[track_caller]
+    pub fn or_default(self) -> &'a mut V {
+        self.zond_collection
+            .push_operation(ZHashMapOperation::EntryOrDefault {
+                state: self.state(),
+            });
+        self.inner.or_default()
+    }
+}
@@ -0,0 +1,421 @@
+//! [`HashSet`]'s analogue with collecting statistics and all corresponding types, structs, traits, etc.
+
+use std::{
+    borrow::Borrow,
+    collections::{
+        hash_map::RandomState,
+        hash_set::{Difference, Drain, Intersection, Iter, SymmetricDifference, Union},
+        HashSet, TryReserveError,
+    },
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{OperationType, Zond, ZondCollection};
+
+/// Describes [`ZHashSet`]'s operation types or, in other words, called methods.
+///
+/// `present` field shows whether the value was in the set before the operation.
+/// So it is a hit for membership checks and a miss otherwise.
+#[derive(Debug, Clone)]
+pub enum ZHashSetOperation<T: Clone> {
+    New,
+    WithCapacity { capacity: usize },
+    WithHasher,
+    WithCapacityAndHasher { capacity: usize },
+    Capacity,
+    Iter,
+    Len,
+    IsEmpty,
+    Drain,
+    Retain,
+    Clear,
+    Hasher,
+    Reserve { additional: usize },
+    TryReserve { additional: usize },
+    ShrinkToFit,
+    ShrinkTo { min_capacity: usize },
+    Difference,
+    SymmetricDifference,
+    Intersection,
+    Union,
+    Contains { value: T, present: bool },
+    Get { value: T, present: bool },
+    IsDisjoint,
+    IsSubset,
+    IsSuperset,
+    Insert { value: T, present: bool },
+    Replace { value: T, present: bool },
+    Remove { value: T, present: bool },
+    Take { value: T, present: bool },
+    IntoHashSet,
+    FromHashSet { from: Vec<T> },
+}
+
+impl<T: Clone> OperationType for ZHashSetOperation<T> {
+    type Capture = ();
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::WithCapacity { .. } => "WithCapacity",
+            Self::WithHasher => "WithHasher",
+            Self::WithCapacityAndHasher { .. } => "WithCapacityAndHasher",
+            Self::Capacity => "Capacity",
+            Self::Iter => "Iter",
+            Self::Len => "Len",
+            Self::IsEmpty => "IsEmpty",
+            Self::Drain => "Drain",
+            Self::Retain => "Retain",
+            Self::Clear => "Clear",
+            Self::Hasher => "Hasher",
+            Self::Reserve { .. } => "Reserve",
+            Self::TryReserve { .. } => "TryReserve",
+            Self::ShrinkToFit => "ShrinkToFit",
+            Self::ShrinkTo { .. } => "ShrinkTo",
+            Self::Difference => "Difference",
+            Self::SymmetricDifference => "SymmetricDifference",
+            Self::Intersection => "Intersection",
+            Self::Union => "Union",
+            Self::Contains { .. } => "Contains",
+            Self::Get { .. } => "Get",
+            Self::IsDisjoint => "IsDisjoint",
+            Self::IsSubset => "IsSubset",
+            Self::IsSuperset => "IsSuperset",
+            Self::Insert { .. } => "Insert",
+            Self::Replace { .. } => "Replace",
+            Self::Remove { .. } => "Remove",
+            Self::Take { .. } => "Take",
+            Self::IntoHashSet => "IntoHashSet",
+            Self::FromHashSet { .. } => "FromHashSet",
+        }
+    }
+
+    fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        match self {
+            Self::WithCapacity { capacity } => visitor("capacity", *capacity),
+            Self::WithCapacityAndHasher { capacity } => visitor("capacity", *capacity),
+            Self::Reserve { additional } => visitor("additional", *additional),
+            Self::TryReserve { additional } => visitor("additional", *additional),
+            Self::ShrinkTo { min_capacity } => visitor("min_capacity", *min_capacity),
+            _ => {}
+        }
+    }
+}
+
+/// `ZHashSet` is a wrapper around [`HashSet`] providing collecting statistics about operations.
+///
+/// Methods that look up by borrowed value, like [`contains`](ZHashSet::contains), require the borrowed form to be
+/// convertible back to `T` via [`ToOwned`] so that the value can be saved in operation.
+pub struct ZHashSet<T: Clone, S = RandomState> {
+    inner: HashSet<T, S>,
+    zond_collection: ZondCollection<ZHashSetOperation<T>>,
+}
+
+impl<T: Clone> ZHashSet<T, RandomState> {
+    #[track_caller]
+    pub fn new(zond: Zond<ZHashSetOperation<T>>) -> Self {
+        let zhashset = Self {
+            inner: HashSet::new(),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashset
+            .zond_collection
+            .push_operation(ZHashSetOperation::New);
+        zhashset
+    }
+
+    #[track_caller]
+    pub fn with_capacity(capacity: usize, zond: Zond<ZHashSetOperation<T>>) -> Self {
+        let zhashset = Self {
+            inner: HashSet::with_capacity(capacity),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashset
+            .zond_collection
+            .push_operation(ZHashSetOperation::WithCapacity { capacity });
+        zhashset
+    }
+}
+
+impl<T: Clone, S> ZHashSet<T, S> {
+    /// Creates `ZHashSet` from existing `HashSet` instance.
+    #[track_caller]
+    pub fn from_hash_set(from: HashSet<T, S>, zond: Zond<ZHashSetOperation<T>>) -> Self {
+        let zhashset = Self {
+            inner: from,
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashset
+            .zond_collection
+            .push_operation(ZHashSetOperation::FromHashSet {
+                from: zhashset.inner.iter().cloned().collect(),
+            });
+        zhashset
+    }
+
+    #[track_caller]
+    pub fn with_hasher(hasher: S, zond: Zond<ZHashSetOperation<T>>) -> Self {
+        let zhashset = Self {
+            inner: HashSet::with_hasher(hasher),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashset
+            .zond_collection
+            .push_operation(ZHashSetOperation::WithHasher);
+        zhashset
+    }
+
+    #[track_caller]
+    pub fn with_capacity_and_hasher(
+        capacity: usize,
+        hasher: S,
+        zond: Zond<ZHashSetOperation<T>>,
+    ) -> Self {
+        let zhashset = Self {
+            inner: HashSet::with_capacity_and_hasher(capacity, hasher),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zhashset
+            .zond_collection
+            .push_operation(ZHashSetOperation::WithCapacityAndHasher { capacity });
+        zhashset
+    }
+
+    #[track_caller]
+    pub fn capacity(&self) -> usize {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Capacity);
+        self.inner.capacity()
+    }
+
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.zond_collection.push_operation(ZHashSetOperation::Iter);
+        self.inner.iter()
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.zond_collection.push_operation(ZHashSetOperation::Len);
+        self.inner.len()
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::IsEmpty);
+        self.inner.is_empty()
+    }
+
+    #[track_caller]
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Drain);
+        self.inner.drain()
+    }
+
+    #[track_caller]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Retain);
+        self.inner.retain(f)
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Clear);
+        self.inner.clear()
+    }
+
+    #[track_caller]
+    pub fn hasher(&self) -> &S {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Hasher);
+        self.inner.hasher()
+    }
+}
+
+impl<T, S> ZHashSet<T, S>
+where
+    T: Clone + Eq + Hash,
+    S: BuildHasher,
+{
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Reserve { additional });
+        self.inner.reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::TryReserve { additional });
+        self.inner.try_reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::ShrinkToFit);
+        self.inner.shrink_to_fit()
+    }
+
+    #[track_caller]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::ShrinkTo { min_capacity });
+        self.inner.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    pub fn difference<'a>(&'a self, other: &'a HashSet<T, S>) -> Difference<'a, T, S> {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Difference);
+        self.inner.difference(other)
+    }
+
+    #[track_caller]
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a HashSet<T, S>,
+    ) -> SymmetricDifference<'a, T, S> {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::SymmetricDifference);
+        self.inner.symmetric_difference(other)
+    }
+
+    #[track_caller]
+    pub fn intersection<'a>(&'a self, other: &'a HashSet<T, S>) -> Intersection<'a, T, S> {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Intersection);
+        self.inner.intersection(other)
+    }
+
+    #[track_caller]
+    pub fn union<'a>(&'a self, other: &'a HashSet<T, S>) -> Union<'a, T, S> {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Union);
+        self.inner.union(other)
+    }
+
+    #[track_caller]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = T> + ?Sized,
+    {
+        let present = self.inner.contains(value);
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Contains {
+                value: value.to_owned(),
+                present,
+            });
+        present
+    }
+
+    #[track_caller]
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = T> + ?Sized,
+    {
+        let got = self.inner.get(value);
+        self.zond_collection.push_operation(ZHashSetOperation::Get {
+            value: value.to_owned(),
+            present: got.is_some(),
+        });
+        got
+    }
+
+    #[track_caller]
+    pub fn is_disjoint(&self, other: &HashSet<T, S>) -> bool {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::IsDisjoint);
+        self.inner.is_disjoint(other)
+    }
+
+    #[track_caller]
+    pub fn is_subset(&self, other: &HashSet<T, S>) -> bool {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::IsSubset);
+        self.inner.is_subset(other)
+    }
+
+    #[track_caller]
+    pub fn is_superset(&self, other: &HashSet<T, S>) -> bool {
+        self.zond_collection
+            .push_operation(ZHashSetOperation::IsSuperset);
+        self.inner.is_superset(other)
+    }
+
+    #[track_caller]
+    pub fn insert(&mut self, value: T) -> bool {
+        let operation_value = value.clone();
+        let inserted = self.inner.insert(value);
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Insert {
+                value: operation_value,
+                present: !inserted,
+            });
+        inserted
+    }
+
+    #[track_caller]
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        let operation_value = value.clone();
+        let replaced = self.inner.replace(value);
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Replace {
+                value: operation_value,
+                present: replaced.is_some(),
+            });
+        replaced
+    }
+
+    #[track_caller]
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = T> + ?Sized,
+    {
+        let present = self.inner.remove(value);
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Remove {
+                value: value.to_owned(),
+                present,
+            });
+        present
+    }
+
+    #[track_caller]
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = T> + ?Sized,
+    {
+        let taken = self.inner.take(value);
+        self.zond_collection
+            .push_operation(ZHashSetOperation::Take {
+                value: value.to_owned(),
+                present: taken.is_some(),
+            });
+        taken
+    }
+}
+
+impl<T: Clone, S> From<ZHashSet<T, S>> for HashSet<T, S> {
+    #[track_caller]
+    fn from(zhashset: ZHashSet<T, S>) -> HashSet<T, S> {
+        let ZHashSet {
+            inner,
+            zond_collection,
+        } = zhashset;
+        zond_collection.push_operation(ZHashSetOperation::IntoHashSet);
+        inner
+    }
+}
@@ -0,0 +1,234 @@
+//! [`LinkedList`]'s analogue with collecting statistics and all corresponding types, structs, traits, etc.
+
+use std::collections::{
+    linked_list::{Iter, IterMut},
+    LinkedList,
+};
+
+use crate::{OperationType, Zond, ZondCollection};
+
+/// Describes [`ZLinkedList`]'s operation types or, in other words, called methods.
+#[derive(Debug, Clone)]
+pub enum ZLinkedListOperation<T: Clone> {
+    New,
+    Append { other: LinkedList<T> },
+    Iter,
+    IterMut,
+    IsEmpty,
+    Len,
+    Clear,
+    Contains { x: T },
+    Front,
+    FrontMut,
+    Back,
+    BackMut,
+    PushFront { elt: T },
+    PopFront,
+    PushBack { elt: T },
+    PopBack,
+    SplitOff { at: usize },
+    IntoLinkedList,
+    FromLinkedList { from: LinkedList<T> },
+}
+
+impl<T: Clone> OperationType for ZLinkedListOperation<T> {
+    type Capture = ();
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::Append { .. } => "Append",
+            Self::Iter => "Iter",
+            Self::IterMut => "IterMut",
+            Self::IsEmpty => "IsEmpty",
+            Self::Len => "Len",
+            Self::Clear => "Clear",
+            Self::Contains { .. } => "Contains",
+            Self::Front => "Front",
+            Self::FrontMut => "FrontMut",
+            Self::Back => "Back",
+            Self::BackMut => "BackMut",
+            Self::PushFront { .. } => "PushFront",
+            Self::PopFront => "PopFront",
+            Self::PushBack { .. } => "PushBack",
+            Self::PopBack => "PopBack",
+            Self::SplitOff { .. } => "SplitOff",
+            Self::IntoLinkedList => "IntoLinkedList",
+            Self::FromLinkedList { .. } => "FromLinkedList",
+        }
+    }
+
+    fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        if let Self::SplitOff { at } = self {
+            visitor("at", *at)
+        }
+    }
+}
+
+/// `ZLinkedList` is a wrapper around [`LinkedList`] providing collecting statistics about operations.
+///
+/// *Attention*. `LinkedList`'s cursors are unstable yet, so they are not available here.
+pub struct ZLinkedList<T: Clone> {
+    inner: LinkedList<T>,
+    zond_collection: ZondCollection<ZLinkedListOperation<T>>,
+}
+
+impl<T: Clone> ZLinkedList<T> {
+    /// Creates `ZLinkedList` from existing `LinkedList` instance.
+    #[track_caller]
+    pub fn from_linked_list(from: LinkedList<T>, zond: Zond<ZLinkedListOperation<T>>) -> Self {
+        let zlinkedlist = Self {
+            inner: from,
+            zond_collection: ZondCollection::new(zond),
+        };
+        zlinkedlist
+            .zond_collection
+            .push_operation(ZLinkedListOperation::FromLinkedList {
+                from: zlinkedlist.inner.clone(),
+            });
+        zlinkedlist
+    }
+
+    #[track_caller]
+    pub fn new(zond: Zond<ZLinkedListOperation<T>>) -> Self {
+        let zlinkedlist = Self {
+            inner: LinkedList::new(),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zlinkedlist
+            .zond_collection
+            .push_operation(ZLinkedListOperation::New);
+        zlinkedlist
+    }
+
+    #[track_caller]
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::Append {
+                other: other.clone(),
+            });
+        self.inner.append(other)
+    }
+
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::Iter);
+        self.inner.iter()
+    }
+
+    #[track_caller]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::IterMut);
+        self.inner.iter_mut()
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::IsEmpty);
+        self.inner.is_empty()
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::Len);
+        self.inner.len()
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::Clear);
+        self.inner.clear()
+    }
+
+    #[track_caller]
+    pub fn front(&self) -> Option<&T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::Front);
+        self.inner.front()
+    }
+
+    #[track_caller]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::FrontMut);
+        self.inner.front_mut()
+    }
+
+    #[track_caller]
+    pub fn back(&self) -> Option<&T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::Back);
+        self.inner.back()
+    }
+
+    #[track_caller]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::BackMut);
+        self.inner.back_mut()
+    }
+
+    #[track_caller]
+    pub fn push_front(&mut self, elt: T) {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::PushFront { elt: elt.clone() });
+        self.inner.push_front(elt)
+    }
+
+    #[track_caller]
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::PopFront);
+        self.inner.pop_front()
+    }
+
+    #[track_caller]
+    pub fn push_back(&mut self, elt: T) {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::PushBack { elt: elt.clone() });
+        self.inner.push_back(elt)
+    }
+
+    #[track_caller]
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::PopBack);
+        self.inner.pop_back()
+    }
+
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::SplitOff { at });
+        self.inner.split_off(at)
+    }
+}
+
+impl<T> ZLinkedList<T>
+where
+    T: Clone + PartialEq,
+{
+    #[track_caller]
+    pub fn contains(&self, x: &T) -> bool {
+        self.zond_collection
+            .push_operation(ZLinkedListOperation::Contains { x: x.clone() });
+        self.inner.contains(x)
+    }
+}
+
+impl<T: Clone> From<ZLinkedList<T>> for LinkedList<T> {
+    #[track_caller]
+    fn from(zlinkedlist: ZLinkedList<T>) -> LinkedList<T> {
+        let ZLinkedList {
+            inner,
+            zond_collection,
+        } = zlinkedlist;
+        zond_collection.push_operation(ZLinkedListOperation::IntoLinkedList);
+        inner
+    }
+}
@@ -0,0 +1,543 @@
+//! Instrumented views into [`ZVec`](crate::zvec::ZVec)'s contents: [`ZSlice`] and [`ZSliceMut`].
+//!
+//! Slice operations are saved in the owning `ZVec`'s operations as [`ZVecOperation::Slice`](crate::zvec::ZVecOperation::Slice).
+
+use std::{
+    cmp::Ordering,
+    slice::{Chunks, ChunksExact, ChunksMut, Iter, IterMut, RChunks, Windows},
+};
+
+use crate::{zvec::ZVecOperation, Capture, Captured, CapturedSlice, ZondCollection};
+
+/// Describes [`ZSlice`]'s and [`ZSliceMut`]'s operation types or, in other words, called methods.
+#[derive(Debug, Clone)]
+pub enum ZSliceOperation<T> {
+    Len,
+    IsEmpty,
+    First,
+    FirstMut,
+    Last,
+    LastMut,
+    SplitFirst,
+    SplitLast,
+    Get { index: usize },
+    GetMut { index: usize },
+    Swap { a: usize, b: usize },
+    Reverse,
+    Iter,
+    IterMut,
+    Windows { size: usize },
+    Chunks { chunk_size: usize },
+    ChunksExact { chunk_size: usize },
+    ChunksMut { chunk_size: usize },
+    RChunks { chunk_size: usize },
+    SplitAt { mid: usize },
+    SplitAtMut { mid: usize },
+    Contains { x: Captured<T> },
+    StartsWith { needle: CapturedSlice<T> },
+    EndsWith { needle: CapturedSlice<T> },
+    BinarySearch { x: Captured<T> },
+    BinarySearchBy,
+    BinarySearchByKey,
+    Sort,
+    SortBy,
+    SortByKey,
+    SortUnstable,
+    SortUnstableBy,
+    SortUnstableByKey,
+    RotateLeft { mid: usize },
+    RotateRight { k: usize },
+    Fill { value: Captured<T> },
+    CloneFromSlice { src: CapturedSlice<T> },
+    ToVec,
+}
+
+impl<T> ZSliceOperation<T> {
+    /// Name of the operation, prefixed with `Slice::` to distinguish it from [`ZVec`](crate::zvec::ZVec)'s own operations.
+    /// Used as [`ZVecOperation::Slice`]'s [`kind`](crate::OperationType::kind).
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Len => "Slice::Len",
+            Self::IsEmpty => "Slice::IsEmpty",
+            Self::First => "Slice::First",
+            Self::FirstMut => "Slice::FirstMut",
+            Self::Last => "Slice::Last",
+            Self::LastMut => "Slice::LastMut",
+            Self::SplitFirst => "Slice::SplitFirst",
+            Self::SplitLast => "Slice::SplitLast",
+            Self::Get { .. } => "Slice::Get",
+            Self::GetMut { .. } => "Slice::GetMut",
+            Self::Swap { .. } => "Slice::Swap",
+            Self::Reverse => "Slice::Reverse",
+            Self::Iter => "Slice::Iter",
+            Self::IterMut => "Slice::IterMut",
+            Self::Windows { .. } => "Slice::Windows",
+            Self::Chunks { .. } => "Slice::Chunks",
+            Self::ChunksExact { .. } => "Slice::ChunksExact",
+            Self::ChunksMut { .. } => "Slice::ChunksMut",
+            Self::RChunks { .. } => "Slice::RChunks",
+            Self::SplitAt { .. } => "Slice::SplitAt",
+            Self::SplitAtMut { .. } => "Slice::SplitAtMut",
+            Self::Contains { .. } => "Slice::Contains",
+            Self::StartsWith { .. } => "Slice::StartsWith",
+            Self::EndsWith { .. } => "Slice::EndsWith",
+            Self::BinarySearch { .. } => "Slice::BinarySearch",
+            Self::BinarySearchBy => "Slice::BinarySearchBy",
+            Self::BinarySearchByKey => "Slice::BinarySearchByKey",
+            Self::Sort => "Slice::Sort",
+            Self::SortBy => "Slice::SortBy",
+            Self::SortByKey => "Slice::SortByKey",
+            Self::SortUnstable => "Slice::SortUnstable",
+            Self::SortUnstableBy => "Slice::SortUnstableBy",
+            Self::SortUnstableByKey => "Slice::SortUnstableByKey",
+            Self::RotateLeft { .. } => "Slice::RotateLeft",
+            Self::RotateRight { .. } => "Slice::RotateRight",
+            Self::Fill { .. } => "Slice::Fill",
+            Self::CloneFromSlice { .. } => "Slice::CloneFromSlice",
+            Self::ToVec => "Slice::ToVec",
+        }
+    }
+
+    /// Passes numeric arguments of the operation to `visitor`.
+    /// Used as [`ZVecOperation::Slice`]'s [`visit_arguments`](crate::OperationType::visit_arguments).
+    pub fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        match self {
+            Self::Get { index } => visitor("index", *index),
+            Self::GetMut { index } => visitor("index", *index),
+            Self::Swap { a, b } => {
+                visitor("a", *a);
+                visitor("b", *b);
+            }
+            Self::Windows { size } => visitor("size", *size),
+            Self::Chunks { chunk_size }
+            | Self::ChunksExact { chunk_size }
+            | Self::ChunksMut { chunk_size }
+            | Self::RChunks { chunk_size } => visitor("chunk_size", *chunk_size),
+            Self::SplitAt { mid } => visitor("mid", *mid),
+            Self::SplitAtMut { mid } => visitor("mid", *mid),
+            Self::RotateLeft { mid } => visitor("mid", *mid),
+            Self::RotateRight { k } => visitor("k", *k),
+            _ => {}
+        }
+    }
+}
+
+// Calls `call` and saves `operation` as `ZVec`'s operation with the call's duration.
+#[track_caller]
+fn timed<T, R>(
+    zond_collection: &ZondCollection<ZVecOperation<T>>,
+    operation: ZSliceOperation<T>,
+    call: impl FnOnce() -> R,
+) -> R {
+    zond_collection.timed(ZVecOperation::Slice(operation), call)
+}
+
+/// Instrumented view into the [`ZVec`](crate::zvec::ZVec)'s contents. Returned by [`ZVec::as_zslice`](crate::zvec::ZVec::as_zslice).
+pub struct ZSlice<'a, T> {
+    inner: &'a [T],
+    zond_collection: &'a ZondCollection<ZVecOperation<T>>,
+}
+
+impl<'a, T> ZSlice<'a, T> {
+    pub(crate) fn new(
+        inner: &'a [T],
+        zond_collection: &'a ZondCollection<ZVecOperation<T>>,
+    ) -> Self {
+        Self {
+            inner,
+            zond_collection,
+        }
+    }
+
+    fn capture(&self) -> &Capture<T> {
+        self.zond_collection.capture()
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        timed(self.zond_collection, ZSliceOperation::Len, || {
+            self.inner.len()
+        })
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        timed(self.zond_collection, ZSliceOperation::IsEmpty, || {
+            self.inner.is_empty()
+        })
+    }
+
+    #[track_caller]
+    pub fn first(&self) -> Option<&'a T> {
+        timed(self.zond_collection, ZSliceOperation::First, || {
+            self.inner.first()
+        })
+    }
+
+    #[track_caller]
+    pub fn last(&self) -> Option<&'a T> {
+        timed(self.zond_collection, ZSliceOperation::Last, || {
+            self.inner.last()
+        })
+    }
+
+    #[track_caller]
+    pub fn split_first(&self) -> Option<(&'a T, &'a [T])> {
+        timed(self.zond_collection, ZSliceOperation::SplitFirst, || {
+            self.inner.split_first()
+        })
+    }
+
+    #[track_caller]
+    pub fn split_last(&self) -> Option<(&'a T, &'a [T])> {
+        timed(self.zond_collection, ZSliceOperation::SplitLast, || {
+            self.inner.split_last()
+        })
+    }
+
+    #[track_caller]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        timed(self.zond_collection, ZSliceOperation::Get { index }, || {
+            self.inner.get(index)
+        })
+    }
+
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'a, T> {
+        timed(self.zond_collection, ZSliceOperation::Iter, || {
+            self.inner.iter()
+        })
+    }
+
+    #[track_caller]
+    pub fn windows(&self, size: usize) -> Windows<'a, T> {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::Windows { size },
+            || self.inner.windows(size),
+        )
+    }
+
+    #[track_caller]
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'a, T> {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::Chunks { chunk_size },
+            || self.inner.chunks(chunk_size),
+        )
+    }
+
+    #[track_caller]
+    pub fn chunks_exact(&self, chunk_size: usize) -> ChunksExact<'a, T> {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::ChunksExact { chunk_size },
+            || self.inner.chunks_exact(chunk_size),
+        )
+    }
+
+    #[track_caller]
+    pub fn rchunks(&self, chunk_size: usize) -> RChunks<'a, T> {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::RChunks { chunk_size },
+            || self.inner.rchunks(chunk_size),
+        )
+    }
+
+    #[track_caller]
+    pub fn split_at(&self, mid: usize) -> (&'a [T], &'a [T]) {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::SplitAt { mid },
+            || self.inner.split_at(mid),
+        )
+    }
+
+    #[track_caller]
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+    {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::BinarySearchBy,
+            || self.inner.binary_search_by(f),
+        )
+    }
+
+    #[track_caller]
+    pub fn binary_search_by_key<B, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+    {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::BinarySearchByKey,
+            || self.inner.binary_search_by_key(b, f),
+        )
+    }
+}
+
+impl<T: Clone> ZSlice<'_, T> {
+    #[track_caller]
+    pub fn to_vec(&self) -> Vec<T> {
+        timed(self.zond_collection, ZSliceOperation::ToVec, || {
+            self.inner.to_vec()
+        })
+    }
+}
+
+impl<T> ZSlice<'_, T>
+where
+    T: PartialEq,
+{
+    #[track_caller]
+    pub fn contains(&self, x: &T) -> bool {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::Contains {
+                x: self.capture().capture(x),
+            },
+            || self.inner.contains(x),
+        )
+    }
+
+    #[track_caller]
+    pub fn starts_with(&self, needle: &[T]) -> bool {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::StartsWith {
+                needle: self.capture().capture_all(needle),
+            },
+            || self.inner.starts_with(needle),
+        )
+    }
+
+    #[track_caller]
+    pub fn ends_with(&self, needle: &[T]) -> bool {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::EndsWith {
+                needle: self.capture().capture_all(needle),
+            },
+            || self.inner.ends_with(needle),
+        )
+    }
+}
+
+impl<T> ZSlice<'_, T>
+where
+    T: Ord,
+{
+    #[track_caller]
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize> {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::BinarySearch {
+                x: self.capture().capture(x),
+            },
+            || self.inner.binary_search(x),
+        )
+    }
+}
+
+/// Instrumented mutable view into the [`ZVec`](crate::zvec::ZVec)'s contents. Returned by [`ZVec::as_zslice_mut`](crate::zvec::ZVec::as_zslice_mut).
+///
+/// Contains only methods that need mutable access. Use [`ZSliceMut::as_zslice`] for the others.
+pub struct ZSliceMut<'a, T> {
+    inner: &'a mut [T],
+    zond_collection: &'a ZondCollection<ZVecOperation<T>>,
+}
+
+impl<'a, T> ZSliceMut<'a, T> {
+    pub(crate) fn new(
+        inner: &'a mut [T],
+        zond_collection: &'a ZondCollection<ZVecOperation<T>>,
+    ) -> Self {
+        Self {
+            inner,
+            zond_collection,
+        }
+    }
+
+    fn capture(&self) -> &Capture<T> {
+        self.zond_collection.capture()
+    }
+
+    /// Reborrows `ZSliceMut` as immutable [`ZSlice`].
+    #[track_caller]
+    pub fn as_zslice(&self) -> ZSlice<'_, T> {
+        ZSlice::new(self.inner, self.zond_collection)
+    }
+
+    #[track_caller]
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        timed(self.zond_collection, ZSliceOperation::FirstMut, || {
+            self.inner.first_mut()
+        })
+    }
+
+    #[track_caller]
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        timed(self.zond_collection, ZSliceOperation::LastMut, || {
+            self.inner.last_mut()
+        })
+    }
+
+    #[track_caller]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::GetMut { index },
+            || self.inner.get_mut(index),
+        )
+    }
+
+    #[track_caller]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        timed(self.zond_collection, ZSliceOperation::Swap { a, b }, || {
+            self.inner.swap(a, b)
+        })
+    }
+
+    #[track_caller]
+    pub fn reverse(&mut self) {
+        timed(self.zond_collection, ZSliceOperation::Reverse, || {
+            self.inner.reverse()
+        })
+    }
+
+    #[track_caller]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        timed(self.zond_collection, ZSliceOperation::IterMut, || {
+            self.inner.iter_mut()
+        })
+    }
+
+    #[track_caller]
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_, T> {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::ChunksMut { chunk_size },
+            || self.inner.chunks_mut(chunk_size),
+        )
+    }
+
+    #[track_caller]
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::SplitAtMut { mid },
+            || self.inner.split_at_mut(mid),
+        )
+    }
+
+    #[track_caller]
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        timed(self.zond_collection, ZSliceOperation::SortBy, || {
+            self.inner.sort_by(compare)
+        })
+    }
+
+    #[track_caller]
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        timed(self.zond_collection, ZSliceOperation::SortByKey, || {
+            self.inner.sort_by_key(f)
+        })
+    }
+
+    #[track_caller]
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::SortUnstableBy,
+            || self.inner.sort_unstable_by(compare),
+        )
+    }
+
+    #[track_caller]
+    pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::SortUnstableByKey,
+            || self.inner.sort_unstable_by_key(f),
+        )
+    }
+
+    #[track_caller]
+    pub fn rotate_left(&mut self, mid: usize) {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::RotateLeft { mid },
+            || self.inner.rotate_left(mid),
+        )
+    }
+
+    #[track_caller]
+    pub fn rotate_right(&mut self, k: usize) {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::RotateRight { k },
+            || self.inner.rotate_right(k),
+        )
+    }
+}
+
+impl<T: Clone> ZSliceMut<'_, T> {
+    #[track_caller]
+    pub fn fill(&mut self, value: T) {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::Fill {
+                value: self.capture().capture(&value),
+            },
+            || self.inner.fill(value),
+        )
+    }
+
+    #[track_caller]
+    pub fn clone_from_slice(&mut self, src: &[T]) {
+        timed(
+            self.zond_collection,
+            ZSliceOperation::CloneFromSlice {
+                src: self.capture().capture_all(src),
+            },
+            || self.inner.clone_from_slice(src),
+        )
+    }
+}
+
+impl<T> ZSliceMut<'_, T>
+where
+    T: Ord,
+{
+    #[track_caller]
+    pub fn sort(&mut self) {
+        timed(self.zond_collection, ZSliceOperation::Sort, || {
+            self.inner.sort()
+        })
+    }
+
+    #[track_caller]
+    pub fn sort_unstable(&mut self) {
+        timed(self.zond_collection, ZSliceOperation::SortUnstable, || {
+            self.inner.sort_unstable()
+        })
+    }
+}
@@ -0,0 +1,422 @@
+//! [`String`]'s analogue with collecting statistics and all corresponding types, structs, traits, etc.
+
+use std::{
+    collections::TryReserveError,
+    ops::{Bound, Deref, RangeBounds},
+    string::Drain,
+};
+
+use crate::{OperationType, Zond, ZondCollection};
+
+/// Describes [`ZString`]'s operation types or, in other words, called methods.
+///
+/// Pushed or inserted strings are not saved, only their lengths in bytes.
+#[derive(Debug, Clone)]
+pub enum ZStringOperation {
+    New,
+    WithCapacity {
+        capacity: usize,
+    },
+    IntoBytes,
+    AsStr,
+    AsMutStr,
+    PushStr {
+        len: usize,
+    },
+    Capacity,
+    Reserve {
+        additional: usize,
+    },
+    ReserveExact {
+        additional: usize,
+    },
+    TryReserve {
+        additional: usize,
+    },
+    TryReserveExact {
+        additional: usize,
+    },
+    ShrinkToFit,
+    ShrinkTo {
+        min_capacity: usize,
+    },
+    Push {
+        ch: char,
+    },
+    AsBytes,
+    Truncate {
+        new_len: usize,
+    },
+    Pop,
+    Remove {
+        idx: usize,
+    },
+    Retain,
+    Insert {
+        idx: usize,
+        ch: char,
+    },
+    InsertStr {
+        idx: usize,
+        len: usize,
+    },
+    Len,
+    IsEmpty,
+    SplitOff {
+        at: usize,
+    },
+    Clear,
+    Drain {
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+    },
+    ReplaceRange {
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+        len: usize,
+    },
+    IntoBoxedStr,
+    Deref,
+    IntoString,
+    FromString {
+        len: usize,
+    },
+}
+
+impl OperationType for ZStringOperation {
+    type Capture = ();
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::WithCapacity { .. } => "WithCapacity",
+            Self::IntoBytes => "IntoBytes",
+            Self::AsStr => "AsStr",
+            Self::AsMutStr => "AsMutStr",
+            Self::PushStr { .. } => "PushStr",
+            Self::Capacity => "Capacity",
+            Self::Reserve { .. } => "Reserve",
+            Self::ReserveExact { .. } => "ReserveExact",
+            Self::TryReserve { .. } => "TryReserve",
+            Self::TryReserveExact { .. } => "TryReserveExact",
+            Self::ShrinkToFit => "ShrinkToFit",
+            Self::ShrinkTo { .. } => "ShrinkTo",
+            Self::Push { .. } => "Push",
+            Self::AsBytes => "AsBytes",
+            Self::Truncate { .. } => "Truncate",
+            Self::Pop => "Pop",
+            Self::Remove { .. } => "Remove",
+            Self::Retain => "Retain",
+            Self::Insert { .. } => "Insert",
+            Self::InsertStr { .. } => "InsertStr",
+            Self::Len => "Len",
+            Self::IsEmpty => "IsEmpty",
+            Self::SplitOff { .. } => "SplitOff",
+            Self::Clear => "Clear",
+            Self::Drain { .. } => "Drain",
+            Self::ReplaceRange { .. } => "ReplaceRange",
+            Self::IntoBoxedStr => "IntoBoxedStr",
+            Self::Deref => "Deref",
+            Self::IntoString => "IntoString",
+            Self::FromString { .. } => "FromString",
+        }
+    }
+
+    fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        match self {
+            Self::WithCapacity { capacity } => visitor("capacity", *capacity),
+            Self::PushStr { len } => visitor("len", *len),
+            Self::Reserve { additional } => visitor("additional", *additional),
+            Self::ReserveExact { additional } => visitor("additional", *additional),
+            Self::TryReserve { additional } => visitor("additional", *additional),
+            Self::TryReserveExact { additional } => visitor("additional", *additional),
+            Self::ShrinkTo { min_capacity } => visitor("min_capacity", *min_capacity),
+            Self::Truncate { new_len } => visitor("new_len", *new_len),
+            Self::Remove { idx } => visitor("idx", *idx),
+            Self::Insert { idx, .. } => visitor("idx", *idx),
+            Self::InsertStr { idx, len } => {
+                visitor("idx", *idx);
+                visitor("len", *len);
+            }
+            Self::SplitOff { at } => visitor("at", *at),
+            Self::ReplaceRange { len, .. } => visitor("len", *len),
+            Self::FromString { len } => visitor("len", *len),
+            _ => {}
+        }
+    }
+}
+
+/// `ZString` is a wrapper around [`String`] providing collecting statistics about operations.
+///
+/// *Attention*. As with [`ZVec`](crate::zvec::ZVec), `str`'s methods are avaliable via an implicit deref() call,
+/// so only [`Deref`](ZStringOperation::Deref) saved when you call them.
+pub struct ZString {
+    inner: String,
+    zond_collection: ZondCollection<ZStringOperation>,
+}
+
+impl ZString {
+    /// Creates `ZString` from existing `String` instance.
+    #[track_caller]
+    pub fn from_string(from: String, zond: Zond<ZStringOperation>) -> Self {
+        let zstring = Self {
+            inner: from,
+            zond_collection: ZondCollection::new(zond),
+        };
+        zstring
+            .zond_collection
+            .push_operation(ZStringOperation::FromString {
+                len: zstring.inner.len(),
+            });
+        zstring
+    }
+
+    #[track_caller]
+    pub fn new(zond: Zond<ZStringOperation>) -> Self {
+        let zstring = Self {
+            inner: String::new(),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zstring
+            .zond_collection
+            .push_operation(ZStringOperation::New);
+        zstring
+    }
+
+    #[track_caller]
+    pub fn with_capacity(capacity: usize, zond: Zond<ZStringOperation>) -> Self {
+        let zstring = Self {
+            inner: String::with_capacity(capacity),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zstring
+            .zond_collection
+            .push_operation(ZStringOperation::WithCapacity { capacity });
+        zstring
+    }
+
+    #[track_caller]
+    pub fn into_bytes(self) -> Vec<u8> {
+        let ZString {
+            inner,
+            zond_collection,
+        } = self;
+        zond_collection.push_operation(ZStringOperation::IntoBytes);
+        inner.into_bytes()
+    }
+
+    #[track_caller]
+    pub fn as_str(&self) -> &str {
+        self.zond_collection.push_operation(ZStringOperation::AsStr);
+        self.inner.as_str()
+    }
+
+    #[track_caller]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        self.zond_collection
+            .push_operation(ZStringOperation::AsMutStr);
+        self.inner.as_mut_str()
+    }
+
+    #[track_caller]
+    pub fn push_str(&mut self, string: &str) {
+        self.zond_collection
+            .push_operation(ZStringOperation::PushStr { len: string.len() });
+        self.inner.push_str(string)
+    }
+
+    #[track_caller]
+    pub fn capacity(&self) -> usize {
+        self.zond_collection
+            .push_operation(ZStringOperation::Capacity);
+        self.inner.capacity()
+    }
+
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        self.zond_collection
+            .push_operation(ZStringOperation::Reserve { additional });
+        self.inner.reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.zond_collection
+            .push_operation(ZStringOperation::ReserveExact { additional });
+        self.inner.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.zond_collection
+            .push_operation(ZStringOperation::TryReserve { additional });
+        self.inner.try_reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.zond_collection
+            .push_operation(ZStringOperation::TryReserveExact { additional });
+        self.inner.try_reserve_exact(additional)
+    }
+
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        self.zond_collection
+            .push_operation(ZStringOperation::ShrinkToFit);
+        self.inner.shrink_to_fit()
+    }
+
+    #[track_caller]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.zond_collection
+            .push_operation(ZStringOperation::ShrinkTo { min_capacity });
+        self.inner.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    pub fn push(&mut self, ch: char) {
+        self.zond_collection
+            .push_operation(ZStringOperation::Push { ch });
+        self.inner.push(ch)
+    }
+
+    #[track_caller]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.zond_collection
+            .push_operation(ZStringOperation::AsBytes);
+        self.inner.as_bytes()
+    }
+
+    #[track_caller]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.zond_collection
+            .push_operation(ZStringOperation::Truncate { new_len });
+        self.inner.truncate(new_len)
+    }
+
+    #[track_caller]
+    pub fn pop(&mut self) -> Option<char> {
+        self.zond_collection.push_operation(ZStringOperation::Pop);
+        self.inner.pop()
+    }
+
+    #[track_caller]
+    pub fn remove(&mut self, idx: usize) -> char {
+        self.zond_collection
+            .push_operation(ZStringOperation::Remove { idx });
+        self.inner.remove(idx)
+    }
+
+    #[track_caller]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.zond_collection
+            .push_operation(ZStringOperation::Retain);
+        self.inner.retain(f)
+    }
+
+    #[track_caller]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        self.zond_collection
+            .push_operation(ZStringOperation::Insert { idx, ch });
+        self.inner.insert(idx, ch)
+    }
+
+    #[track_caller]
+    pub fn insert_str(&mut self, idx: usize, string: &str) {
+        self.zond_collection
+            .push_operation(ZStringOperation::InsertStr {
+                idx,
+                len: string.len(),
+            });
+        self.inner.insert_str(idx, string)
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.zond_collection.push_operation(ZStringOperation::Len);
+        self.inner.len()
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.zond_collection
+            .push_operation(ZStringOperation::IsEmpty);
+        self.inner.is_empty()
+    }
+
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> String {
+        self.zond_collection
+            .push_operation(ZStringOperation::SplitOff { at });
+        self.inner.split_off(at)
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.zond_collection.push_operation(ZStringOperation::Clear);
+        self.inner.clear()
+    }
+
+    #[track_caller]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.zond_collection
+            .push_operation(ZStringOperation::Drain {
+                start_bound: range.start_bound().cloned(),
+                end_bound: range.end_bound().cloned(),
+            });
+        self.inner.drain(range)
+    }
+
+    #[track_caller]
+    pub fn replace_range<R>(&mut self, range: R, replace_with: &str)
+    where
+        R: RangeBounds<usize>,
+    {
+        self.zond_collection
+            .push_operation(ZStringOperation::ReplaceRange {
+                start_bound: range.start_bound().cloned(),
+                end_bound: range.end_bound().cloned(),
+                len: replace_with.len(),
+            });
+        self.inner.replace_range(range, replace_with)
+    }
+
+    #[track_caller]
+    pub fn into_boxed_str(self) -> Box<str> {
+        let ZString {
+            inner,
+            zond_collection,
+        } = self;
+        zond_collection.push_operation(ZStringOperation::IntoBoxedStr);
+        inner.into_boxed_str()
+    }
+}
+
+impl Deref for ZString {
+    type Target = str;
+
+    #[track_caller]
+    fn deref(&self) -> &Self::Target {
+        self.zond_collection.push_operation(ZStringOperation::Deref);
+        self.inner.deref()
+    }
+}
+
+impl From<ZString> for String {
+    #[track_caller]
+    fn from(zstring: ZString) -> String {
+        let ZString {
+            inner,
+            zond_collection,
+        } = zstring;
+        zond_collection.push_operation(ZStringOperation::IntoString);
+        inner
+    }
+}
@@ -2,26 +2,39 @@
 
 use std::{
     collections::TryReserveError,
+    iter::FusedIterator,
     mem::MaybeUninit,
-    ops::{Bound, Deref, RangeBounds},
-    vec::{Drain, Splice},
+    ops::{
+        Bound, Deref, Index, IndexMut, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive,
+        RangeTo, RangeToInclusive,
+    },
+    slice, vec,
 };
 
-use crate::{OperationType, Zond, ZondCollection};
+use crate::{
+    zslice::{ZSlice, ZSliceMut, ZSliceOperation},
+    Capture, Captured, CapturedSlice, OperationType, Snapshot, Timer, Zond, ZondCollection,
+};
 
 /// Describes [`ZVec`]'s operation types or, in other words, called methods.
+///
+/// `outcome` is the value returned by the method and `removed` is count of elements removed by it.
+/// Operations with them are saved after the call, so they aren't saved if the call panics.
 #[derive(Debug, Clone)]
-pub enum ZVecOperation<T: Clone> {
+pub enum ZVecOperation<T> {
     New,
     WithCapacity {
         capacity: usize,
     },
+    /// `ptr` is saved as an address so that the operation can be sent between threads.
     FromRawParts {
-        ptr: *mut T,
+        ptr: usize,
         length: usize,
         capacity: usize,
     },
-    Capacity,
+    Capacity {
+        outcome: usize,
+    },
     Reserve {
         additional: usize,
     },
@@ -30,9 +43,11 @@ pub enum ZVecOperation<T: Clone> {
     },
     TryReserve {
         additional: usize,
+        outcome: Result<(), TryReserveError>,
     },
     TryReserveExact {
         additional: usize,
+        outcome: Result<(), TryReserveError>,
     },
     ShrinkToFit,
     ShrinkTo {
@@ -41,6 +56,7 @@ pub enum ZVecOperation<T: Clone> {
     IntoBoxedSlice,
     Truncate {
         len: usize,
+        removed: usize,
     },
     AsSlice,
     AsMutSlice,
@@ -51,34 +67,58 @@ pub enum ZVecOperation<T: Clone> {
     },
     SwapRemove {
         index: usize,
+        outcome: Captured<T>,
     },
     Insert {
         index: usize,
-        element: T,
+        element: Captured<T>,
     },
     Remove {
         index: usize,
+        outcome: Captured<T>,
+    },
+    Retain {
+        removed: usize,
+    },
+    RetainMut {
+        removed: usize,
+    },
+    DedupByKey {
+        removed: usize,
+    },
+    DedupBy {
+        removed: usize,
     },
-    Retain,
-    RetainMut,
-    DedupByKey,
-    DedupBy,
     Push {
-        value: T,
+        value: Captured<T>,
+    },
+    Pop {
+        outcome: Option<Captured<T>>,
     },
-    Pop,
+    /// `source` is id of [`ZVec`] whose elements were moved, see [`append_zvec`](ZVec::append_zvec).
     Append {
-        other: Vec<T>,
+        other: CapturedSlice<T>,
+        source: Option<usize>,
     },
+    /// Vector is changed when returned iterator is dropped, so the operation is saved then
+    /// and its duration, snapshot and [`Reallocated`](ZVecOperation::Reallocated) include the changes.
     Drain {
         start_bound: Bound<usize>,
         end_bound: Bound<usize>,
+        removed: usize,
+    },
+    Clear {
+        removed: usize,
+    },
+    Len {
+        outcome: usize,
+    },
+    IsEmpty {
+        outcome: bool,
     },
-    Clear,
-    Len,
-    IsEmpty,
     SplitOff {
         at: usize,
+        outcome: CapturedSlice<T>,
     },
     ResizeWith {
         new_len: usize,
@@ -87,51 +127,236 @@ pub enum ZVecOperation<T: Clone> {
     SpareCapacityMut,
     Resize {
         new_len: usize,
-        value: T,
+        value: Captured<T>,
     },
     ExtendFromSlice {
-        other: Vec<T>,
+        other: CapturedSlice<T>,
     },
     ExtendFromWithin {
         src_start_bound: Bound<usize>,
         src_end_bound: Bound<usize>,
     },
-    Dedup,
+    Dedup {
+        removed: usize,
+    },
+    /// Vector is changed when returned iterator is dropped, so the operation is saved then
+    /// and its duration, snapshot and [`Reallocated`](ZVecOperation::Reallocated) include the changes.
     Splice {
         start_bound: Bound<usize>,
         end_bound: Bound<usize>,
     },
     Deref,
+    Index {
+        index: usize,
+    },
+    IndexMut {
+        index: usize,
+    },
+    IndexRange {
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+    },
+    IndexRangeMut {
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+    },
+    Iter,
+    IterMut,
+    IntoIter,
+    /// Iterator created by [`Iter`](ZVecOperation::Iter), [`IterMut`](ZVecOperation::IterMut) or
+    /// [`IntoIter`](ZVecOperation::IntoIter) was dropped. `exhausted` is `false` when iteration was stopped early.
+    IterEnd {
+        yielded: usize,
+        exhausted: bool,
+    },
     IntoVec,
     FromVec {
-        from: Vec<T>,
+        from: CapturedSlice<T>,
+    },
+    /// Elements were moved to new vector with id `child`, see [`split_off_zvec`](ZVec::split_off_zvec).
+    SplitOffZVec {
+        at: usize,
+        child: usize,
+        removed: usize,
+    },
+    /// Elements were moved to new vector with id `child`, see [`drain_zvec`](ZVec::drain_zvec).
+    DrainZVec {
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+        child: usize,
+        removed: usize,
+    },
+    /// Vector was created by [`split_off_zvec`](ZVec::split_off_zvec) of vector with id `parent`.
+    FromSplitOff {
+        parent: usize,
+        at: usize,
+    },
+    /// Vector was created by [`drain_zvec`](ZVec::drain_zvec) of vector with id `parent`.
+    FromDrain {
+        parent: usize,
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+    },
+    /// All elements were moved to vector with id `target` by its [`append_zvec`](ZVec::append_zvec).
+    AppendedTo {
+        target: usize,
+        removed: usize,
+    },
+    Slice(ZSliceOperation<T>),
+    /// Vector's buffer was reallocated by the previous operation. Saved only if [`Zond::with_snapshots`] is used.
+    Reallocated {
+        old_capacity: usize,
+        new_capacity: usize,
     },
 }
 
-impl<T: Clone> OperationType for ZVecOperation<T> {}
+impl<T> OperationType for ZVecOperation<T> {
+    type Capture = Capture<T>;
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::WithCapacity { .. } => "WithCapacity",
+            Self::FromRawParts { .. } => "FromRawParts",
+            Self::Capacity { .. } => "Capacity",
+            Self::Reserve { .. } => "Reserve",
+            Self::ReserveExact { .. } => "ReserveExact",
+            Self::TryReserve { .. } => "TryReserve",
+            Self::TryReserveExact { .. } => "TryReserveExact",
+            Self::ShrinkToFit => "ShrinkToFit",
+            Self::ShrinkTo { .. } => "ShrinkTo",
+            Self::IntoBoxedSlice => "IntoBoxedSlice",
+            Self::Truncate { .. } => "Truncate",
+            Self::AsSlice => "AsSlice",
+            Self::AsMutSlice => "AsMutSlice",
+            Self::AsPtr => "AsPtr",
+            Self::AsMutPtr => "AsMutPtr",
+            Self::SetLen { .. } => "SetLen",
+            Self::SwapRemove { .. } => "SwapRemove",
+            Self::Insert { .. } => "Insert",
+            Self::Remove { .. } => "Remove",
+            Self::Retain { .. } => "Retain",
+            Self::RetainMut { .. } => "RetainMut",
+            Self::DedupByKey { .. } => "DedupByKey",
+            Self::DedupBy { .. } => "DedupBy",
+            Self::Push { .. } => "Push",
+            Self::Pop { .. } => "Pop",
+            Self::Append { .. } => "Append",
+            Self::Drain { .. } => "Drain",
+            Self::Clear { .. } => "Clear",
+            Self::Len { .. } => "Len",
+            Self::IsEmpty { .. } => "IsEmpty",
+            Self::SplitOff { .. } => "SplitOff",
+            Self::ResizeWith { .. } => "ResizeWith",
+            Self::Leak => "Leak",
+            Self::SpareCapacityMut => "SpareCapacityMut",
+            Self::Resize { .. } => "Resize",
+            Self::ExtendFromSlice { .. } => "ExtendFromSlice",
+            Self::ExtendFromWithin { .. } => "ExtendFromWithin",
+            Self::Dedup { .. } => "Dedup",
+            Self::Splice { .. } => "Splice",
+            Self::Deref => "Deref",
+            Self::Index { .. } => "Index",
+            Self::IndexMut { .. } => "IndexMut",
+            Self::IndexRange { .. } => "IndexRange",
+            Self::IndexRangeMut { .. } => "IndexRangeMut",
+            Self::Iter => "Iter",
+            Self::IterMut => "IterMut",
+            Self::IntoIter => "IntoIter",
+            Self::IterEnd { .. } => "IterEnd",
+            Self::IntoVec => "IntoVec",
+            Self::FromVec { .. } => "FromVec",
+            Self::SplitOffZVec { .. } => "SplitOffZVec",
+            Self::DrainZVec { .. } => "DrainZVec",
+            Self::FromSplitOff { .. } => "FromSplitOff",
+            Self::FromDrain { .. } => "FromDrain",
+            Self::AppendedTo { .. } => "AppendedTo",
+            Self::Slice(operation) => operation.kind(),
+            Self::Reallocated { .. } => "Reallocated",
+        }
+    }
+
+    fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        match self {
+            Self::WithCapacity { capacity } => visitor("capacity", *capacity),
+            Self::FromRawParts {
+                length, capacity, ..
+            } => {
+                visitor("length", *length);
+                visitor("capacity", *capacity);
+            }
+            Self::Reserve { additional } => visitor("additional", *additional),
+            Self::ReserveExact { additional } => visitor("additional", *additional),
+            Self::TryReserve { additional, .. } => visitor("additional", *additional),
+            Self::TryReserveExact { additional, .. } => visitor("additional", *additional),
+            Self::ShrinkTo { min_capacity } => visitor("min_capacity", *min_capacity),
+            Self::Capacity { outcome } => visitor("outcome", *outcome),
+            Self::Truncate { len, removed } => {
+                visitor("len", *len);
+                visitor("removed", *removed);
+            }
+            Self::SetLen { new_len } => visitor("new_len", *new_len),
+            Self::SwapRemove { index, .. } => visitor("index", *index),
+            Self::Insert { index, .. } => visitor("index", *index),
+            Self::Remove { index, .. } => visitor("index", *index),
+            Self::Retain { removed }
+            | Self::RetainMut { removed }
+            | Self::DedupByKey { removed }
+            | Self::DedupBy { removed }
+            | Self::Drain { removed, .. }
+            | Self::Clear { removed }
+            | Self::Dedup { removed }
+            | Self::AppendedTo { removed, .. }
+            | Self::DrainZVec { removed, .. } => visitor("removed", *removed),
+            Self::SplitOffZVec { at, removed, .. } => {
+                visitor("at", *at);
+                visitor("removed", *removed);
+            }
+            Self::Len { outcome } => visitor("outcome", *outcome),
+            Self::SplitOff { at, .. } => visitor("at", *at),
+            Self::FromSplitOff { at, .. } => visitor("at", *at),
+            Self::ResizeWith { new_len } => visitor("new_len", *new_len),
+            Self::Resize { new_len, .. } => visitor("new_len", *new_len),
+            Self::Index { index } => visitor("index", *index),
+            Self::IndexMut { index } => visitor("index", *index),
+            Self::IterEnd { yielded, .. } => visitor("yielded", *yielded),
+            Self::Reallocated {
+                old_capacity,
+                new_capacity,
+            } => {
+                visitor("old_capacity", *old_capacity);
+                visitor("new_capacity", *new_capacity);
+            }
+            Self::Slice(operation) => operation.visit_arguments(visitor),
+            _ => {}
+        }
+    }
+}
 
 /// `ZVec` is a wrapper around [`Vec`] providing collecting statistics about operations.
 ///
 /// *Attention*. Many `Vec`'s methods are avaliable via an implicit deref() call. So when you call them, only [`Deref`](ZVecOperation::Deref) saved.\
-/// Later I'll implement wrapper around slice for collecting its operations.
-pub struct ZVec<T: Clone> {
+/// Use [`as_zslice`](ZVec::as_zslice) and [`as_zslice_mut`](ZVec::as_zslice_mut) to collect slice's operations too.
+pub struct ZVec<T> {
     inner: Vec<T>,
     zond_collection: ZondCollection<ZVecOperation<T>>,
 }
 
-impl<T: Clone> ZVec<T> {
+impl<T> ZVec<T> {
     /// Creates `Zvec` from existing `Vec` instance.
+    #[track_caller]
     pub fn from_vec(from: Vec<T>, zond: Zond<ZVecOperation<T>>) -> Self {
         let zvec = Self {
             inner: from,
             zond_collection: ZondCollection::new(zond),
         };
         zvec.zond_collection.push_operation(ZVecOperation::FromVec {
-            from: zvec.inner.clone(),
+            from: zvec.zond_collection.capture().capture_all(&zvec.inner),
         });
         zvec
     }
 
+    #[track_caller]
     pub fn new(zond: Zond<ZVecOperation<T>>) -> Self {
         let zvec = Self {
             inner: Vec::new(),
@@ -141,6 +366,7 @@ impl<T: Clone> ZVec<T> {
         zvec
     }
 
+    #[track_caller]
     pub fn with_capacity(capacity: usize, zond: Zond<ZVecOperation<T>>) -> Self {
         let zvec = Self {
             inner: Vec::with_capacity(capacity),
@@ -151,6 +377,12 @@ impl<T: Clone> ZVec<T> {
         zvec
     }
 
+    /// Creates `ZVec` directly from a pointer, a length and a capacity.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Vec::from_raw_parts`].
+    #[track_caller]
     pub unsafe fn from_raw_parts(
         ptr: *mut T,
         length: usize,
@@ -163,254 +395,508 @@ impl<T: Clone> ZVec<T> {
         };
         zvec.zond_collection
             .push_operation(ZVecOperation::FromRawParts {
-                ptr,
+                ptr: ptr as usize,
                 length,
                 capacity,
             });
         zvec
     }
 
+    #[track_caller]
     pub fn capacity(&self) -> usize {
-        self.zond_collection.push_operation(ZVecOperation::Capacity);
-        self.inner.capacity()
+        let timer = self.zond_collection.start_timer();
+        let capacity = self.inner.capacity();
+        self.zond_collection.push_timed_operation(
+            ZVecOperation::Capacity { outcome: capacity },
+            timer,
+            None,
+        );
+        capacity
     }
 
+    #[track_caller]
     pub fn reserve(&mut self, additional: usize) {
-        self.zond_collection
-            .push_operation(ZVecOperation::Reserve { additional });
-        self.inner.reserve(additional)
+        self.mutate(ZVecOperation::Reserve { additional }, |inner| {
+            inner.reserve(additional)
+        })
     }
 
+    #[track_caller]
     pub fn reserve_exact(&mut self, additional: usize) {
-        self.zond_collection
-            .push_operation(ZVecOperation::ReserveExact { additional });
-        self.inner.reserve_exact(additional)
+        self.mutate(ZVecOperation::ReserveExact { additional }, |inner| {
+            inner.reserve_exact(additional)
+        })
     }
 
+    #[track_caller]
     pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        self.zond_collection
-            .push_operation(ZVecOperation::TryReserve { additional });
-        self.inner.try_reserve(additional)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let outcome = self.inner.try_reserve(additional);
+        self.push_mutating_operation(
+            ZVecOperation::TryReserve {
+                additional,
+                outcome: outcome.clone(),
+            },
+            timer,
+            before,
+        );
+        outcome
     }
 
+    #[track_caller]
     pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        self.zond_collection
-            .push_operation(ZVecOperation::TryReserveExact { additional });
-        self.inner.try_reserve_exact(additional)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let outcome = self.inner.try_reserve_exact(additional);
+        self.push_mutating_operation(
+            ZVecOperation::TryReserveExact {
+                additional,
+                outcome: outcome.clone(),
+            },
+            timer,
+            before,
+        );
+        outcome
     }
 
+    #[track_caller]
     pub fn shrink_to_fit(&mut self) {
-        self.zond_collection
-            .push_operation(ZVecOperation::ShrinkToFit);
-        self.inner.shrink_to_fit()
+        self.mutate(ZVecOperation::ShrinkToFit, |inner| inner.shrink_to_fit())
     }
 
+    #[track_caller]
     pub fn shrink_to(&mut self, min_capacity: usize) {
-        self.zond_collection
-            .push_operation(ZVecOperation::ShrinkTo { min_capacity });
-        self.inner.shrink_to(min_capacity)
+        self.mutate(ZVecOperation::ShrinkTo { min_capacity }, |inner| {
+            inner.shrink_to(min_capacity)
+        })
     }
 
+    #[track_caller]
     pub fn into_boxed_slice(self) -> Box<[T]> {
         let ZVec {
             inner,
             zond_collection,
         } = self;
-        zond_collection.push_operation(ZVecOperation::IntoBoxedSlice);
-        inner.into_boxed_slice()
+        zond_collection.timed(ZVecOperation::IntoBoxedSlice, || inner.into_boxed_slice())
     }
 
+    #[track_caller]
     pub fn truncate(&mut self, len: usize) {
-        self.zond_collection
-            .push_operation(ZVecOperation::Truncate { len });
-        self.inner.truncate(len)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let old_len = self.inner.len();
+        self.inner.truncate(len);
+        self.push_mutating_operation(
+            ZVecOperation::Truncate {
+                len,
+                removed: old_len - self.inner.len(),
+            },
+            timer,
+            before,
+        );
     }
 
+    #[track_caller]
     pub fn as_slice(&self) -> &[T] {
-        self.zond_collection.push_operation(ZVecOperation::AsSlice);
-        self.inner.as_slice()
+        self.zond_collection
+            .timed(ZVecOperation::AsSlice, || self.inner.as_slice())
+    }
+
+    /// Returns instrumented view into the vector's contents.
+    /// Its operations are saved as [`Slice`](ZVecOperation::Slice).
+    #[track_caller]
+    pub fn as_zslice(&self) -> ZSlice<'_, T> {
+        ZSlice::new(&self.inner, &self.zond_collection)
+    }
+
+    /// Returns instrumented mutable view into the vector's contents.
+    /// Its operations are saved as [`Slice`](ZVecOperation::Slice).
+    #[track_caller]
+    pub fn as_zslice_mut(&mut self) -> ZSliceMut<'_, T> {
+        ZSliceMut::new(&mut self.inner, &self.zond_collection)
+    }
+
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.zond_collection.push_operation(ZVecOperation::Iter);
+        Iter {
+            inner: self.inner.iter(),
+            zond_collection: &self.zond_collection,
+            yielded: 0,
+        }
+    }
+
+    #[track_caller]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.zond_collection.push_operation(ZVecOperation::IterMut);
+        IterMut {
+            inner: self.inner.iter_mut(),
+            zond_collection: &self.zond_collection,
+            yielded: 0,
+        }
     }
 
+    #[track_caller]
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         self.zond_collection
-            .push_operation(ZVecOperation::AsMutSlice);
-        self.inner.as_mut_slice()
+            .timed(ZVecOperation::AsMutSlice, || self.inner.as_mut_slice())
     }
 
+    #[track_caller]
     pub fn as_ptr(&self) -> *const T {
-        self.zond_collection.push_operation(ZVecOperation::AsPtr);
-        self.inner.as_ptr()
+        self.zond_collection
+            .timed(ZVecOperation::AsPtr, || self.inner.as_ptr())
     }
 
+    #[track_caller]
     pub fn as_mut_ptr(&mut self) -> *mut T {
-        self.zond_collection.push_operation(ZVecOperation::AsMutPtr);
-        self.inner.as_mut_ptr()
+        self.zond_collection
+            .timed(ZVecOperation::AsMutPtr, || self.inner.as_mut_ptr())
     }
 
+    /// Forces the length of the vector to `new_len`.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Vec::set_len`].
+    #[track_caller]
     pub unsafe fn set_len(&mut self, new_len: usize) {
-        self.zond_collection
-            .push_operation(ZVecOperation::SetLen { new_len });
-        self.inner.set_len(new_len)
+        self.mutate(ZVecOperation::SetLen { new_len }, |inner| {
+            inner.set_len(new_len)
+        })
     }
 
+    #[track_caller]
     pub fn swap_remove(&mut self, index: usize) -> T {
-        self.zond_collection
-            .push_operation(ZVecOperation::SwapRemove { index });
-        self.inner.swap_remove(index)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let value = self.inner.swap_remove(index);
+        self.push_mutating_operation(
+            ZVecOperation::SwapRemove {
+                index,
+                outcome: self.zond_collection.capture().capture(&value),
+            },
+            timer,
+            before,
+        );
+        value
     }
 
+    #[track_caller]
     pub fn insert(&mut self, index: usize, element: T) {
-        self.zond_collection.push_operation(ZVecOperation::Insert {
-            index,
-            element: element.clone(),
-        });
-        self.inner.insert(index, element)
+        self.mutate(
+            ZVecOperation::Insert {
+                index,
+                element: self.zond_collection.capture().capture(&element),
+            },
+            |inner| inner.insert(index, element),
+        )
     }
 
+    #[track_caller]
     pub fn remove(&mut self, index: usize) -> T {
-        self.zond_collection
-            .push_operation(ZVecOperation::Remove { index });
-        self.inner.remove(index)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let value = self.inner.remove(index);
+        self.push_mutating_operation(
+            ZVecOperation::Remove {
+                index,
+                outcome: self.zond_collection.capture().capture(&value),
+            },
+            timer,
+            before,
+        );
+        value
     }
 
+    #[track_caller]
     pub fn retain<F>(&mut self, f: F)
     where
         F: FnMut(&T) -> bool,
     {
-        self.zond_collection.push_operation(ZVecOperation::Retain);
-        self.inner.retain(f)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let len = self.inner.len();
+        self.inner.retain(f);
+        self.push_mutating_operation(
+            ZVecOperation::Retain {
+                removed: len - self.inner.len(),
+            },
+            timer,
+            before,
+        );
     }
 
+    #[track_caller]
     pub fn retain_mut<F>(&mut self, f: F)
     where
         F: FnMut(&mut T) -> bool,
     {
-        self.zond_collection
-            .push_operation(ZVecOperation::RetainMut);
-        self.inner.retain_mut(f)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let len = self.inner.len();
+        self.inner.retain_mut(f);
+        self.push_mutating_operation(
+            ZVecOperation::RetainMut {
+                removed: len - self.inner.len(),
+            },
+            timer,
+            before,
+        );
     }
 
+    #[track_caller]
     pub fn dedup_by_key<F, K>(&mut self, key: F)
     where
         F: FnMut(&mut T) -> K,
         K: PartialEq,
     {
-        self.zond_collection
-            .push_operation(ZVecOperation::DedupByKey);
-        self.inner.dedup_by_key(key)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let len = self.inner.len();
+        self.inner.dedup_by_key(key);
+        self.push_mutating_operation(
+            ZVecOperation::DedupByKey {
+                removed: len - self.inner.len(),
+            },
+            timer,
+            before,
+        );
     }
 
+    #[track_caller]
     pub fn dedup_by<F>(&mut self, same_bucket: F)
     where
         F: FnMut(&mut T, &mut T) -> bool,
     {
-        self.zond_collection.push_operation(ZVecOperation::DedupBy);
-        self.inner.dedup_by(same_bucket)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let len = self.inner.len();
+        self.inner.dedup_by(same_bucket);
+        self.push_mutating_operation(
+            ZVecOperation::DedupBy {
+                removed: len - self.inner.len(),
+            },
+            timer,
+            before,
+        );
     }
 
+    #[track_caller]
     pub fn push(&mut self, value: T) {
-        self.zond_collection.push_operation(ZVecOperation::Push {
-            value: value.clone(),
-        });
-        self.inner.push(value)
+        self.mutate(
+            ZVecOperation::Push {
+                value: self.zond_collection.capture().capture(&value),
+            },
+            |inner| inner.push(value),
+        )
     }
 
+    #[track_caller]
     pub fn pop(&mut self) -> Option<T> {
-        self.zond_collection.push_operation(ZVecOperation::Pop);
-        self.inner.pop()
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let value = self.inner.pop();
+        self.push_mutating_operation(
+            ZVecOperation::Pop {
+                outcome: value
+                    .as_ref()
+                    .map(|value| self.zond_collection.capture().capture(value)),
+            },
+            timer,
+            before,
+        );
+        value
     }
 
+    #[track_caller]
     pub fn append(&mut self, other: &mut Vec<T>) {
-        self.zond_collection.push_operation(ZVecOperation::Append {
-            other: other.clone(),
-        });
-        self.inner.append(other)
+        self.mutate(
+            ZVecOperation::Append {
+                other: self.zond_collection.capture().capture_all(other),
+                source: None,
+            },
+            |inner| inner.append(other),
+        )
+    }
+
+    /// Same as [`append`](ZVec::append), but `other`'s id is saved too, so the elements can be traced between vectors.
+    /// `other` saves [`AppendedTo`](ZVecOperation::AppendedTo).
+    #[track_caller]
+    pub fn append_zvec(&mut self, other: &mut ZVec<T>) {
+        let other_timer = other.zond_collection.start_timer();
+        let other_before = other.state();
+        let removed = other.inner.len();
+        self.mutate(
+            ZVecOperation::Append {
+                other: self.zond_collection.capture().capture_all(&other.inner),
+                source: Some(other.zond_collection.id()),
+            },
+            |inner| inner.append(&mut other.inner),
+        );
+        other.push_mutating_operation(
+            ZVecOperation::AppendedTo {
+                target: self.zond_collection.id(),
+                removed,
+            },
+            other_timer,
+            other_before,
+        );
     }
 
+    /// Unlike [`Vec::drain`] returns [`Drain`] instead of [`vec::Drain`], so the operation is saved when the iterator is dropped.
+    #[track_caller]
     pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
     where
         R: RangeBounds<usize>,
     {
-        self.zond_collection.push_operation(ZVecOperation::Drain {
-            start_bound: range.start_bound().cloned(),
-            end_bound: range.end_bound().cloned(),
-        });
-        self.inner.drain(range)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let start_bound = range.start_bound().cloned();
+        let end_bound = range.end_bound().cloned();
+        let vec = BorrowedVec(&mut self.inner);
+        // SAFETY: `self.inner` is borrowed for the iterator's lifetime and `vec` is read only after the iterator is dropped.
+        let inner = unsafe { &mut *vec.0 }.drain(range);
+        let operation = ZVecOperation::Drain {
+            start_bound,
+            end_bound,
+            removed: inner.len(),
+        };
+        Drain {
+            inner,
+            _deferred: DeferredOperation::new(&self.zond_collection, vec, before, operation, timer),
+        }
+    }
+
+    /// Same as [`drain`](ZVec::drain), but removed elements are collected to new `ZVec` constructed with the same [`Zond`].
+    /// This vector saves [`DrainZVec`](ZVecOperation::DrainZVec) with new vector's id
+    /// and new vector saves [`FromDrain`](ZVecOperation::FromDrain) with this vector's id.
+    #[track_caller]
+    pub fn drain_zvec<R>(&mut self, range: R) -> ZVec<T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let start_bound = range.start_bound().cloned();
+        let end_bound = range.end_bound().cloned();
+        let drained: Vec<T> = self.inner.drain(range).collect();
+        let removed = drained.len();
+        self.derive(
+            drained,
+            timer,
+            before,
+            |child| ZVecOperation::DrainZVec {
+                start_bound,
+                end_bound,
+                child,
+                removed,
+            },
+            ZVecOperation::FromDrain {
+                parent: self.zond_collection.id(),
+                start_bound,
+                end_bound,
+            },
+        )
     }
 
+    #[track_caller]
     pub fn clear(&mut self) {
-        self.zond_collection.push_operation(ZVecOperation::Clear);
-        self.inner.clear()
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let len = self.inner.len();
+        self.inner.clear();
+        self.push_mutating_operation(ZVecOperation::Clear { removed: len }, timer, before);
     }
 
+    #[track_caller]
     pub fn len(&self) -> usize {
-        self.zond_collection.push_operation(ZVecOperation::Len);
-        self.inner.len()
+        let timer = self.zond_collection.start_timer();
+        let len = self.inner.len();
+        self.zond_collection
+            .push_timed_operation(ZVecOperation::Len { outcome: len }, timer, None);
+        len
     }
 
+    #[track_caller]
     pub fn is_empty(&self) -> bool {
-        self.zond_collection.push_operation(ZVecOperation::IsEmpty);
-        self.inner.is_empty()
+        let timer = self.zond_collection.start_timer();
+        let is_empty = self.inner.is_empty();
+        self.zond_collection.push_timed_operation(
+            ZVecOperation::IsEmpty { outcome: is_empty },
+            timer,
+            None,
+        );
+        is_empty
     }
 
+    #[track_caller]
     pub fn split_off(&mut self, at: usize) -> Vec<T> {
-        self.zond_collection
-            .push_operation(ZVecOperation::SplitOff { at });
-        self.inner.split_off(at)
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let other = self.inner.split_off(at);
+        self.push_mutating_operation(
+            ZVecOperation::SplitOff {
+                at,
+                outcome: self.zond_collection.capture().capture_all(&other),
+            },
+            timer,
+            before,
+        );
+        other
+    }
+
+    /// Same as [`split_off`](ZVec::split_off), but returns `ZVec` constructed with the same [`Zond`].
+    /// This vector saves [`SplitOffZVec`](ZVecOperation::SplitOffZVec) with new vector's id
+    /// and new vector saves [`FromSplitOff`](ZVecOperation::FromSplitOff) with this vector's id.
+    #[track_caller]
+    pub fn split_off_zvec(&mut self, at: usize) -> ZVec<T> {
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let other = self.inner.split_off(at);
+        let removed = other.len();
+        self.derive(
+            other,
+            timer,
+            before,
+            |child| ZVecOperation::SplitOffZVec { at, child, removed },
+            ZVecOperation::FromSplitOff {
+                parent: self.zond_collection.id(),
+                at,
+            },
+        )
     }
 
+    #[track_caller]
     pub fn resize_with<F>(&mut self, new_len: usize, f: F)
     where
         F: FnMut() -> T,
     {
-        self.zond_collection
-            .push_operation(ZVecOperation::ResizeWith { new_len });
-        self.inner.resize_with(new_len, f)
+        self.mutate(ZVecOperation::ResizeWith { new_len }, |inner| {
+            inner.resize_with(new_len, f)
+        })
     }
 
+    #[track_caller]
     pub fn leak<'a>(self) -> &'a mut [T] {
         let ZVec {
             inner,
             zond_collection,
         } = self;
-        zond_collection.push_operation(ZVecOperation::Leak);
-        inner.leak()
+        zond_collection.timed(ZVecOperation::Leak, || inner.leak())
     }
 
+    #[track_caller]
     pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
         self.zond_collection
-            .push_operation(ZVecOperation::SpareCapacityMut);
-        self.inner.spare_capacity_mut()
-    }
-
-    pub fn resize(&mut self, new_len: usize, value: T) {
-        self.zond_collection.push_operation(ZVecOperation::Resize {
-            new_len,
-            value: value.clone(),
-        });
-        self.inner.resize(new_len, value)
-    }
-
-    pub fn extend_from_slice(&mut self, other: &[T]) {
-        self.zond_collection
-            .push_operation(ZVecOperation::ExtendFromSlice {
-                other: other.to_vec(),
-            });
-        self.inner.extend_from_slice(other)
-    }
-
-    pub fn extend_from_within<R>(&mut self, src: R)
-    where
-        R: RangeBounds<usize>,
-    {
-        self.zond_collection
-            .push_operation(ZVecOperation::ExtendFromWithin {
-                src_start_bound: src.start_bound().cloned(),
-                src_end_bound: src.end_bound().cloned(),
-            });
-        self.inner.extend_from_within(src)
+            .timed(ZVecOperation::SpareCapacityMut, || {
+                self.inner.spare_capacity_mut()
+            })
     }
 
+    /// Unlike [`Vec::splice`] returns [`Splice`] instead of [`vec::Splice`], so the operation is saved when the iterator is dropped.
+    #[track_caller]
     pub fn splice<I, R>(
         &mut self,
         range: R,
@@ -420,34 +906,243 @@ impl<T: Clone> ZVec<T> {
         R: RangeBounds<usize>,
         I: IntoIterator<Item = T>,
     {
-        self.zond_collection.push_operation(ZVecOperation::Splice {
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let operation = ZVecOperation::Splice {
             start_bound: range.start_bound().cloned(),
             end_bound: range.end_bound().cloned(),
+        };
+        let vec = BorrowedVec(&mut self.inner);
+        // SAFETY: `self.inner` is borrowed for the iterator's lifetime and `vec` is read only after the iterator is dropped.
+        let inner = unsafe { &mut *vec.0 }.splice(range, replace_with);
+        Splice {
+            inner,
+            _deferred: DeferredOperation::new(&self.zond_collection, vec, before, operation, timer),
+        }
+    }
+}
+
+// Vector's state to compare before and after operation.
+#[derive(Clone, Copy)]
+struct State {
+    len: usize,
+    capacity: usize,
+    ptr: usize,
+}
+
+impl State {
+    fn of<T>(vec: &Vec<T>) -> Self {
+        Self {
+            len: vec.len(),
+            capacity: vec.capacity(),
+            ptr: vec.as_ptr() as usize,
+        }
+    }
+}
+
+// Saves operation that changed vector together with its snapshot.
+// `Reallocated` is saved right after it if vector's buffer was changed.
+#[track_caller]
+fn push_mutating_operation<T>(
+    zond_collection: &ZondCollection<ZVecOperation<T>>,
+    operation: ZVecOperation<T>,
+    timer: Timer,
+    before: Option<State>,
+    vec: &Vec<T>,
+) {
+    let Some(before) = before else {
+        zond_collection.push_timed_operation(operation, timer, None);
+        return;
+    };
+    let after = State::of(vec);
+    let snapshot = Snapshot {
+        len_before: before.len,
+        capacity_before: before.capacity,
+        len_after: after.len,
+        capacity_after: after.capacity,
+    };
+    zond_collection.push_timed_operation(operation, timer, Some(snapshot));
+    if before.capacity != after.capacity || before.ptr != after.ptr {
+        zond_collection.push_operation(ZVecOperation::Reallocated {
+            old_capacity: before.capacity,
+            new_capacity: after.capacity,
         });
-        self.inner.splice(range, replace_with)
+    }
+}
+
+impl<T> ZVec<T> {
+    // It is saved only if `Zond::with_snapshots` is used.
+    fn state(&self) -> Option<State> {
+        self.zond_collection
+            .snapshots()
+            .then(|| State::of(&self.inner))
+    }
+
+    #[track_caller]
+    fn push_mutating_operation(
+        &self,
+        operation: ZVecOperation<T>,
+        timer: Timer,
+        before: Option<State>,
+    ) {
+        push_mutating_operation(&self.zond_collection, operation, timer, before, &self.inner);
+    }
+
+    // Constructs vector from elements taken from this one. This vector saves `operation` with new vector's id
+    // and new vector saves `child_operation` that must contain this vector's id.
+    #[track_caller]
+    fn derive(
+        &mut self,
+        inner: Vec<T>,
+        timer: Timer,
+        before: Option<State>,
+        operation: impl FnOnce(usize) -> ZVecOperation<T>,
+        child_operation: ZVecOperation<T>,
+    ) -> ZVec<T> {
+        let zvec = Self {
+            inner,
+            zond_collection: ZondCollection::new(self.zond_collection.zond()),
+        };
+        self.push_mutating_operation(operation(zvec.zond_collection.id()), timer, before);
+        zvec.zond_collection.push_operation(child_operation);
+        zvec
+    }
+
+    // Like `ZondCollection::timed`, but for operations that change vector.
+    #[track_caller]
+    fn mutate<R>(&mut self, operation: ZVecOperation<T>, call: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let result = call(&mut self.inner);
+        self.push_mutating_operation(operation, timer, before);
+        result
+    }
+}
+
+impl<T: Clone> ZVec<T> {
+    #[track_caller]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        self.mutate(
+            ZVecOperation::Resize {
+                new_len,
+                value: self.zond_collection.capture().capture(&value),
+            },
+            |inner| inner.resize(new_len, value),
+        )
+    }
+
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.mutate(
+            ZVecOperation::ExtendFromSlice {
+                other: self.zond_collection.capture().capture_all(other),
+            },
+            |inner| inner.extend_from_slice(other),
+        )
+    }
+
+    #[track_caller]
+    pub fn extend_from_within<R>(&mut self, src: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        self.mutate(
+            ZVecOperation::ExtendFromWithin {
+                src_start_bound: src.start_bound().cloned(),
+                src_end_bound: src.end_bound().cloned(),
+            },
+            |inner| inner.extend_from_within(src),
+        )
     }
 }
 
 impl<T> ZVec<T>
 where
-    T: Clone + PartialEq,
+    T: PartialEq,
 {
+    #[track_caller]
     pub fn dedup(&mut self) {
-        self.zond_collection.push_operation(ZVecOperation::Dedup);
-        self.inner.dedup()
+        let timer = self.zond_collection.start_timer();
+        let before = self.state();
+        let len = self.inner.len();
+        self.inner.dedup();
+        self.push_mutating_operation(
+            ZVecOperation::Dedup {
+                removed: len - self.inner.len(),
+            },
+            timer,
+            before,
+        );
     }
 }
 
-impl<T: Clone> Deref for ZVec<T> {
+impl<T> Deref for ZVec<T> {
     type Target = [T];
 
+    #[track_caller]
     fn deref(&self) -> &Self::Target {
-        self.zond_collection.push_operation(ZVecOperation::Deref);
-        self.inner.deref()
+        self.zond_collection
+            .timed(ZVecOperation::Deref, || self.inner.deref())
+    }
+}
+
+impl<T> Index<usize> for ZVec<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        self.zond_collection
+            .timed(ZVecOperation::Index { index }, || &self.inner[index])
+    }
+}
+
+impl<T> IndexMut<usize> for ZVec<T> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.zond_collection
+            .timed(ZVecOperation::IndexMut { index }, || &mut self.inner[index])
     }
 }
 
-impl<T: Clone> From<ZVec<T>> for Vec<T> {
+// Generic `impl<R: RangeBounds<usize>> Index<R>` would conflict with `Index<usize>`, so every range type gets its own impl.
+macro_rules! impl_index_range {
+    ($($range:ty),*) => {$(
+        impl<T> Index<$range> for ZVec<T> {
+            type Output = [T];
+
+            #[track_caller]
+            fn index(&self, index: $range) -> &Self::Output {
+                self.zond_collection.timed(ZVecOperation::IndexRange {
+                        start_bound: index.start_bound().cloned(),
+                        end_bound: index.end_bound().cloned(),
+                    }, || &self.inner[index])
+            }
+        }
+
+        impl<T> IndexMut<$range> for ZVec<T> {
+            #[track_caller]
+            fn index_mut(&mut self, index: $range) -> &mut Self::Output {
+                self.zond_collection.timed(ZVecOperation::IndexRangeMut {
+                        start_bound: index.start_bound().cloned(),
+                        end_bound: index.end_bound().cloned(),
+                    }, || &mut self.inner[index])
+            }
+        }
+    )*};
+}
+
+impl_index_range!(
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>,
+    (Bound<usize>, Bound<usize>)
+);
+
+impl<T> From<ZVec<T>> for Vec<T> {
+    #[track_caller]
     fn from(zvec: ZVec<T>) -> Vec<T> {
         let ZVec {
             inner,
@@ -457,3 +1152,222 @@ impl<T: Clone> From<ZVec<T>> for Vec<T> {
         inner
     }
 }
+
+impl<'a, T> IntoIterator for &'a ZVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[track_caller]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ZVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[track_caller]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for ZVec<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[track_caller]
+    fn into_iter(self) -> Self::IntoIter {
+        let ZVec {
+            inner,
+            zond_collection,
+        } = self;
+        zond_collection.push_operation(ZVecOperation::IntoIter);
+        IntoIter {
+            inner: inner.into_iter(),
+            zond_collection,
+            yielded: 0,
+        }
+    }
+}
+
+/// Immutable [`ZVec`]'s iterator. Saves [`IterEnd`](ZVecOperation::IterEnd) when dropped.
+pub struct Iter<'a, T> {
+    inner: slice::Iter<'a, T>,
+    zond_collection: &'a ZondCollection<ZVecOperation<T>>,
+    yielded: usize,
+}
+
+/// Mutable [`ZVec`]'s iterator. Saves [`IterEnd`](ZVecOperation::IterEnd) when dropped.
+pub struct IterMut<'a, T> {
+    inner: slice::IterMut<'a, T>,
+    zond_collection: &'a ZondCollection<ZVecOperation<T>>,
+    yielded: usize,
+}
+
+/// Owning [`ZVec`]'s iterator. Saves [`IterEnd`](ZVecOperation::IterEnd) when dropped.
+///
+/// It takes `ZVec`'s operations over, so they are handled after the iterator is dropped.
+pub struct IntoIter<T> {
+    inner: vec::IntoIter<T>,
+    zond_collection: ZondCollection<ZVecOperation<T>>,
+    yielded: usize,
+}
+
+// Iterators differ only in inner iterator and item, so they count yielded elements the same way.
+macro_rules! impl_iterator {
+    ($($iter:ty => $item:ty, [$($generics:tt)*]);*) => {$(
+        impl<$($generics)*> Iterator for $iter {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let next = self.inner.next();
+                if next.is_some() {
+                    self.yielded += 1;
+                }
+                next
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<$($generics)*> DoubleEndedIterator for $iter {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let next = self.inner.next_back();
+                if next.is_some() {
+                    self.yielded += 1;
+                }
+                next
+            }
+        }
+
+        impl<$($generics)*> ExactSizeIterator for $iter {}
+
+        impl<$($generics)*> FusedIterator for $iter {}
+
+        impl<$($generics)*> Drop for $iter {
+            // Iteration can be finished without extra `next` call that returns `None`, e.g. by `zip`.
+            fn drop(&mut self) {
+                self.zond_collection.push_operation(ZVecOperation::IterEnd {
+                    yielded: self.yielded,
+                    exhausted: self.inner.len() == 0,
+                });
+            }
+        }
+    )*};
+}
+
+impl_iterator!(
+    Iter<'a, T> => &'a T, ['a, T];
+    IterMut<'a, T> => &'a mut T, ['a, T];
+    IntoIter<T> => T, [T]
+);
+
+// Vector that is borrowed by iterator. Unlike reference it can be kept next to the iterator,
+// but it must be read only after the iterator is dropped.
+struct BorrowedVec<T>(*mut Vec<T>);
+
+// SAFETY: it is used as `&mut Vec<T>` that is owned by the iterator.
+unsafe impl<T: Send> Send for BorrowedVec<T> {}
+unsafe impl<T: Sync> Sync for BorrowedVec<T> {}
+
+// Saves operation when dropped. Must be the last field of iterator,
+// so the operation is saved after the iterator has changed vector and is timed until then.
+struct DeferredOperation<'a, T> {
+    zond_collection: &'a ZondCollection<ZVecOperation<T>>,
+    vec: BorrowedVec<T>,
+    before: Option<State>,
+    operation: Option<(ZVecOperation<T>, Timer)>,
+}
+
+impl<'a, T> DeferredOperation<'a, T> {
+    fn new(
+        zond_collection: &'a ZondCollection<ZVecOperation<T>>,
+        vec: BorrowedVec<T>,
+        before: Option<State>,
+        operation: ZVecOperation<T>,
+        timer: Timer,
+    ) -> Self {
+        Self {
+            zond_collection,
+            vec,
+            before,
+            operation: Some((operation, timer)),
+        }
+    }
+}
+
+impl<T> Drop for DeferredOperation<'_, T> {
+    fn drop(&mut self) {
+        if let Some((operation, timer)) = self.operation.take() {
+            // SAFETY: the iterator is already dropped, so nothing else borrows vector.
+            let vec = unsafe { &*self.vec.0 };
+            push_mutating_operation(self.zond_collection, operation, timer, self.before, vec);
+        }
+    }
+}
+
+/// [`ZVec`]'s draining iterator. Saves [`Drain`](ZVecOperation::Drain) when dropped.
+pub struct Drain<'a, T> {
+    inner: vec::Drain<'a, T>,
+    // Only dropped.
+    _deferred: DeferredOperation<'a, T>,
+}
+
+impl<T> Drain<'_, T> {
+    pub fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+/// [`ZVec`]'s splicing iterator. Saves [`Splice`](ZVecOperation::Splice) when dropped.
+pub struct Splice<'a, I: Iterator + 'a> {
+    inner: vec::Splice<'a, I>,
+    // Only dropped.
+    _deferred: DeferredOperation<'a, I::Item>,
+}
+
+impl<I: Iterator> Iterator for Splice<'_, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I: Iterator> DoubleEndedIterator for Splice<'_, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<I: Iterator> ExactSizeIterator for Splice<'_, I> {}
@@ -0,0 +1,610 @@
+//! [`VecDeque`]'s analogue with collecting statistics and all corresponding types, structs, traits, etc.
+
+use std::{
+    collections::{
+        vec_deque::{Drain, Iter, IterMut},
+        TryReserveError, VecDeque,
+    },
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{OperationType, Zond, ZondCollection};
+
+/// Describes [`ZVecDeque`]'s operation types or, in other words, called methods.
+#[derive(Debug, Clone)]
+pub enum ZVecDequeOperation<T: Clone> {
+    New,
+    WithCapacity {
+        capacity: usize,
+    },
+    Get {
+        index: usize,
+    },
+    GetMut {
+        index: usize,
+    },
+    Swap {
+        i: usize,
+        j: usize,
+    },
+    Capacity,
+    Reserve {
+        additional: usize,
+    },
+    ReserveExact {
+        additional: usize,
+    },
+    TryReserve {
+        additional: usize,
+    },
+    TryReserveExact {
+        additional: usize,
+    },
+    ShrinkToFit,
+    ShrinkTo {
+        min_capacity: usize,
+    },
+    Truncate {
+        len: usize,
+    },
+    Iter,
+    IterMut,
+    AsSlices,
+    AsMutSlices,
+    Len,
+    IsEmpty,
+    Range {
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+    },
+    RangeMut {
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+    },
+    Drain {
+        start_bound: Bound<usize>,
+        end_bound: Bound<usize>,
+    },
+    Clear,
+    Contains {
+        x: T,
+    },
+    Front,
+    FrontMut,
+    Back,
+    BackMut,
+    PopFront,
+    PopBack,
+    PushFront {
+        value: T,
+    },
+    PushBack {
+        value: T,
+    },
+    SwapRemoveFront {
+        index: usize,
+    },
+    SwapRemoveBack {
+        index: usize,
+    },
+    Insert {
+        index: usize,
+        value: T,
+    },
+    Remove {
+        index: usize,
+    },
+    SplitOff {
+        at: usize,
+    },
+    Append {
+        other: VecDeque<T>,
+    },
+    Retain,
+    RetainMut,
+    ResizeWith {
+        new_len: usize,
+    },
+    MakeContiguous,
+    RotateLeft {
+        n: usize,
+    },
+    RotateRight {
+        n: usize,
+    },
+    Resize {
+        new_len: usize,
+        value: T,
+    },
+    IntoVecDeque,
+    FromVecDeque {
+        from: VecDeque<T>,
+    },
+}
+
+impl<T: Clone> OperationType for ZVecDequeOperation<T> {
+    type Capture = ();
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::New => "New",
+            Self::WithCapacity { .. } => "WithCapacity",
+            Self::Get { .. } => "Get",
+            Self::GetMut { .. } => "GetMut",
+            Self::Swap { .. } => "Swap",
+            Self::Capacity => "Capacity",
+            Self::Reserve { .. } => "Reserve",
+            Self::ReserveExact { .. } => "ReserveExact",
+            Self::TryReserve { .. } => "TryReserve",
+            Self::TryReserveExact { .. } => "TryReserveExact",
+            Self::ShrinkToFit => "ShrinkToFit",
+            Self::ShrinkTo { .. } => "ShrinkTo",
+            Self::Truncate { .. } => "Truncate",
+            Self::Iter => "Iter",
+            Self::IterMut => "IterMut",
+            Self::AsSlices => "AsSlices",
+            Self::AsMutSlices => "AsMutSlices",
+            Self::Len => "Len",
+            Self::IsEmpty => "IsEmpty",
+            Self::Range { .. } => "Range",
+            Self::RangeMut { .. } => "RangeMut",
+            Self::Drain { .. } => "Drain",
+            Self::Clear => "Clear",
+            Self::Contains { .. } => "Contains",
+            Self::Front => "Front",
+            Self::FrontMut => "FrontMut",
+            Self::Back => "Back",
+            Self::BackMut => "BackMut",
+            Self::PopFront => "PopFront",
+            Self::PopBack => "PopBack",
+            Self::PushFront { .. } => "PushFront",
+            Self::PushBack { .. } => "PushBack",
+            Self::SwapRemoveFront { .. } => "SwapRemoveFront",
+            Self::SwapRemoveBack { .. } => "SwapRemoveBack",
+            Self::Insert { .. } => "Insert",
+            Self::Remove { .. } => "Remove",
+            Self::SplitOff { .. } => "SplitOff",
+            Self::Append { .. } => "Append",
+            Self::Retain => "Retain",
+            Self::RetainMut => "RetainMut",
+            Self::ResizeWith { .. } => "ResizeWith",
+            Self::MakeContiguous => "MakeContiguous",
+            Self::RotateLeft { .. } => "RotateLeft",
+            Self::RotateRight { .. } => "RotateRight",
+            Self::Resize { .. } => "Resize",
+            Self::IntoVecDeque => "IntoVecDeque",
+            Self::FromVecDeque { .. } => "FromVecDeque",
+        }
+    }
+
+    fn visit_arguments(&self, visitor: &mut dyn FnMut(&'static str, usize)) {
+        match self {
+            Self::WithCapacity { capacity } => visitor("capacity", *capacity),
+            Self::Get { index } => visitor("index", *index),
+            Self::GetMut { index } => visitor("index", *index),
+            Self::Swap { i, j } => {
+                visitor("i", *i);
+                visitor("j", *j);
+            }
+            Self::Reserve { additional } => visitor("additional", *additional),
+            Self::ReserveExact { additional } => visitor("additional", *additional),
+            Self::TryReserve { additional } => visitor("additional", *additional),
+            Self::TryReserveExact { additional } => visitor("additional", *additional),
+            Self::ShrinkTo { min_capacity } => visitor("min_capacity", *min_capacity),
+            Self::Truncate { len } => visitor("len", *len),
+            Self::SwapRemoveFront { index } => visitor("index", *index),
+            Self::SwapRemoveBack { index } => visitor("index", *index),
+            Self::Insert { index, .. } => visitor("index", *index),
+            Self::Remove { index } => visitor("index", *index),
+            Self::SplitOff { at } => visitor("at", *at),
+            Self::ResizeWith { new_len } => visitor("new_len", *new_len),
+            Self::RotateLeft { n } => visitor("n", *n),
+            Self::RotateRight { n } => visitor("n", *n),
+            Self::Resize { new_len, .. } => visitor("new_len", *new_len),
+            _ => {}
+        }
+    }
+}
+
+/// `ZVecDeque` is a wrapper around [`VecDeque`] providing collecting statistics about operations.
+pub struct ZVecDeque<T: Clone> {
+    inner: VecDeque<T>,
+    zond_collection: ZondCollection<ZVecDequeOperation<T>>,
+}
+
+impl<T: Clone> ZVecDeque<T> {
+    /// Creates `ZVecDeque` from existing `VecDeque` instance.
+    #[track_caller]
+    pub fn from_vec_deque(from: VecDeque<T>, zond: Zond<ZVecDequeOperation<T>>) -> Self {
+        let zvecdeque = Self {
+            inner: from,
+            zond_collection: ZondCollection::new(zond),
+        };
+        zvecdeque
+            .zond_collection
+            .push_operation(ZVecDequeOperation::FromVecDeque {
+                from: zvecdeque.inner.clone(),
+            });
+        zvecdeque
+    }
+
+    #[track_caller]
+    pub fn new(zond: Zond<ZVecDequeOperation<T>>) -> Self {
+        let zvecdeque = Self {
+            inner: VecDeque::new(),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zvecdeque
+            .zond_collection
+            .push_operation(ZVecDequeOperation::New);
+        zvecdeque
+    }
+
+    #[track_caller]
+    pub fn with_capacity(capacity: usize, zond: Zond<ZVecDequeOperation<T>>) -> Self {
+        let zvecdeque = Self {
+            inner: VecDeque::with_capacity(capacity),
+            zond_collection: ZondCollection::new(zond),
+        };
+        zvecdeque
+            .zond_collection
+            .push_operation(ZVecDequeOperation::WithCapacity { capacity });
+        zvecdeque
+    }
+
+    #[track_caller]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Get { index });
+        self.inner.get(index)
+    }
+
+    #[track_caller]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::GetMut { index });
+        self.inner.get_mut(index)
+    }
+
+    #[track_caller]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Swap { i, j });
+        self.inner.swap(i, j)
+    }
+
+    #[track_caller]
+    pub fn capacity(&self) -> usize {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Capacity);
+        self.inner.capacity()
+    }
+
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Reserve { additional });
+        self.inner.reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::ReserveExact { additional });
+        self.inner.reserve_exact(additional)
+    }
+
+    #[track_caller]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::TryReserve { additional });
+        self.inner.try_reserve(additional)
+    }
+
+    #[track_caller]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::TryReserveExact { additional });
+        self.inner.try_reserve_exact(additional)
+    }
+
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::ShrinkToFit);
+        self.inner.shrink_to_fit()
+    }
+
+    #[track_caller]
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::ShrinkTo { min_capacity });
+        self.inner.shrink_to(min_capacity)
+    }
+
+    #[track_caller]
+    pub fn truncate(&mut self, len: usize) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Truncate { len });
+        self.inner.truncate(len)
+    }
+
+    #[track_caller]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Iter);
+        self.inner.iter()
+    }
+
+    #[track_caller]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::IterMut);
+        self.inner.iter_mut()
+    }
+
+    #[track_caller]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::AsSlices);
+        self.inner.as_slices()
+    }
+
+    #[track_caller]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::AsMutSlices);
+        self.inner.as_mut_slices()
+    }
+
+    #[track_caller]
+    pub fn len(&self) -> usize {
+        self.zond_collection.push_operation(ZVecDequeOperation::Len);
+        self.inner.len()
+    }
+
+    #[track_caller]
+    pub fn is_empty(&self) -> bool {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::IsEmpty);
+        self.inner.is_empty()
+    }
+
+    #[track_caller]
+    pub fn range<R>(&self, range: R) -> Iter<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Range {
+                start_bound: range.start_bound().cloned(),
+                end_bound: range.end_bound().cloned(),
+            });
+        self.inner.range(range)
+    }
+
+    #[track_caller]
+    pub fn range_mut<R>(&mut self, range: R) -> IterMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::RangeMut {
+                start_bound: range.start_bound().cloned(),
+                end_bound: range.end_bound().cloned(),
+            });
+        self.inner.range_mut(range)
+    }
+
+    #[track_caller]
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Drain {
+                start_bound: range.start_bound().cloned(),
+                end_bound: range.end_bound().cloned(),
+            });
+        self.inner.drain(range)
+    }
+
+    #[track_caller]
+    pub fn clear(&mut self) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Clear);
+        self.inner.clear()
+    }
+
+    #[track_caller]
+    pub fn front(&self) -> Option<&T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Front);
+        self.inner.front()
+    }
+
+    #[track_caller]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::FrontMut);
+        self.inner.front_mut()
+    }
+
+    #[track_caller]
+    pub fn back(&self) -> Option<&T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Back);
+        self.inner.back()
+    }
+
+    #[track_caller]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::BackMut);
+        self.inner.back_mut()
+    }
+
+    #[track_caller]
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::PopFront);
+        self.inner.pop_front()
+    }
+
+    #[track_caller]
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::PopBack);
+        self.inner.pop_back()
+    }
+
+    #[track_caller]
+    pub fn push_front(&mut self, value: T) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::PushFront {
+                value: value.clone(),
+            });
+        self.inner.push_front(value)
+    }
+
+    #[track_caller]
+    pub fn push_back(&mut self, value: T) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::PushBack {
+                value: value.clone(),
+            });
+        self.inner.push_back(value)
+    }
+
+    #[track_caller]
+    pub fn swap_remove_front(&mut self, index: usize) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::SwapRemoveFront { index });
+        self.inner.swap_remove_front(index)
+    }
+
+    #[track_caller]
+    pub fn swap_remove_back(&mut self, index: usize) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::SwapRemoveBack { index });
+        self.inner.swap_remove_back(index)
+    }
+
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Insert {
+                index,
+                value: value.clone(),
+            });
+        self.inner.insert(index, value)
+    }
+
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Remove { index });
+        self.inner.remove(index)
+    }
+
+    #[track_caller]
+    pub fn split_off(&mut self, at: usize) -> VecDeque<T> {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::SplitOff { at });
+        self.inner.split_off(at)
+    }
+
+    #[track_caller]
+    pub fn append(&mut self, other: &mut VecDeque<T>) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Append {
+                other: other.clone(),
+            });
+        self.inner.append(other)
+    }
+
+    #[track_caller]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Retain);
+        self.inner.retain(f)
+    }
+
+    #[track_caller]
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::RetainMut);
+        self.inner.retain_mut(f)
+    }
+
+    #[track_caller]
+    pub fn resize_with<F>(&mut self, new_len: usize, generator: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::ResizeWith { new_len });
+        self.inner.resize_with(new_len, generator)
+    }
+
+    #[track_caller]
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::MakeContiguous);
+        self.inner.make_contiguous()
+    }
+
+    #[track_caller]
+    pub fn rotate_left(&mut self, n: usize) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::RotateLeft { n });
+        self.inner.rotate_left(n)
+    }
+
+    #[track_caller]
+    pub fn rotate_right(&mut self, n: usize) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::RotateRight { n });
+        self.inner.rotate_right(n)
+    }
+
+    #[track_caller]
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Resize {
+                new_len,
+                value: value.clone(),
+            });
+        self.inner.resize(new_len, value)
+    }
+}
+
+impl<T> ZVecDeque<T>
+where
+    T: Clone + PartialEq,
+{
+    #[track_caller]
+    pub fn contains(&self, x: &T) -> bool {
+        self.zond_collection
+            .push_operation(ZVecDequeOperation::Contains { x: x.clone() });
+        self.inner.contains(x)
+    }
+}
+
+impl<T: Clone> From<ZVecDeque<T>> for VecDeque<T> {
+    #[track_caller]
+    fn from(zvecdeque: ZVecDeque<T>) -> VecDeque<T> {
+        let ZVecDeque {
+            inner,
+            zond_collection,
+        } = zvecdeque;
+        zond_collection.push_operation(ZVecDequeOperation::IntoVecDeque);
+        inner
+    }
+}
@@ -0,0 +1,71 @@
+use std::{num::NonZeroUsize, sync::mpsc};
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    CollectionInfo, DroppedOperations, OperationType, Operations, OverflowStrategy, Policy, Zond,
+    ZondHandler,
+};
+
+// Sends kinds of handled operations and reports about dropped ones.
+struct EventHandler(mpsc::Sender<String>);
+
+impl ZondHandler<ZVecOperation<usize>> for EventHandler {
+    fn handle(&self, _collection: &CollectionInfo, operations: Operations<ZVecOperation<usize>>) {
+        let kinds: Vec<_> = operations
+            .iter()
+            .map(|operation| operation.get_type().kind())
+            .collect();
+        self.0.send(format!("{kinds:?}")).unwrap();
+    }
+
+    fn handle_dropped(&self, _collection: &CollectionInfo, dropped: DroppedOperations) {
+        self.0.send(format!("{dropped:?}")).unwrap();
+    }
+}
+
+fn run(strategy: OverflowStrategy) -> Vec<String> {
+    let (sender, reciever) = mpsc::channel();
+    let zond = Zond::new(EventHandler(sender), Policy::on_drop_only())
+        .with_buffer_limit(NonZeroUsize::new(3).unwrap(), strategy);
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    zvec.push(1);
+    zvec.push(2);
+    zvec.pop();
+    zvec.clear();
+    zvec.push(3);
+    drop(zvec);
+    reciever.try_iter().collect()
+}
+
+#[test]
+pub fn overflow_strategies() {
+    assert_eq!(
+        vec![
+            r#"["New", "Push", "Push"]"#,
+            r#"["Pop", "Clear", "Push"]"#,
+            r#"[]"#,
+        ],
+        run(OverflowStrategy::FlushEarly)
+    );
+    assert_eq!(
+        vec![
+            r#"["Pop", "Clear", "Push"]"#,
+            r#"DroppedOperations { count: 3, kinds: {} }"#,
+        ],
+        run(OverflowStrategy::DropOldest)
+    );
+    assert_eq!(
+        vec![
+            r#"["New", "Push", "Push"]"#,
+            r#"DroppedOperations { count: 3, kinds: {} }"#,
+        ],
+        run(OverflowStrategy::DropNewest)
+    );
+    assert_eq!(
+        vec![
+            r#"["New", "Push", "Push"]"#,
+            r#"DroppedOperations { count: 3, kinds: {"Clear": 1, "Pop": 1, "Push": 1} }"#,
+        ],
+        run(OverflowStrategy::Aggregate)
+    );
+}
@@ -0,0 +1,73 @@
+use std::{mem, sync::mpsc};
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    Capture, Captured, CapturedSlice, Policy, Zond,
+};
+
+mod common;
+
+use common::Handler;
+
+// Neither `Clone` nor `Hash`.
+#[derive(Debug)]
+struct Token(u32);
+
+#[test]
+pub fn capture_without_clone() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_capture(Capture::debug());
+    let mut zvec: ZVec<Token> = ZVec::new(zond.clone());
+    zvec.push(Token(1));
+    zvec.insert(0, Token(2));
+    assert_eq!(2, zvec.as_slice()[0].0);
+    drop(zvec);
+
+    let mut zvec: ZVec<Token> = ZVec::new(zond.with_capture(Capture::size()));
+    zvec.push(Token(3));
+    drop(zvec);
+
+    let operations: Vec<_> = reciever
+        .into_iter()
+        .map(|(_, operation)| format!("{:?}", operation.get_type()))
+        .collect();
+    assert_eq!(
+        vec![
+            "New",
+            r#"Push { value: Debug("Token(1)") }"#,
+            r#"Insert { index: 0, element: Debug("Token(2)") }"#,
+            "AsSlice",
+            "New",
+            "Push { value: Size(4) }",
+        ],
+        operations
+    );
+}
+
+#[test]
+pub fn capture_large_elements() {
+    let (sender, reciever) = mpsc::channel();
+
+    // Element isn't stored inline, so other representations stay small.
+    assert!(mem::size_of::<Captured<[u8; 1024]>>() <= mem::size_of::<String>() + 8);
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+    let zvec: ZVec<[u8; 1024]> = ZVec::from_vec(vec![[0; 1024]; 1000], zond.clone());
+    drop(zvec);
+    let zvec: ZVec<[u8; 1024]> =
+        ZVec::from_vec(vec![[0; 1024]; 1000], zond.with_capture(Capture::size()));
+    drop(zvec);
+
+    let froms: Vec<_> = reciever
+        .into_iter()
+        .map(|(_, operation)| match operation.get_type() {
+            ZVecOperation::FromVec { from } => from.clone(),
+            operation => panic!("unexpected operation {operation:?}"),
+        })
+        .collect();
+    assert_eq!(
+        vec![CapturedSlice::Count(1000), CapturedSlice::Size(1024 * 1000)],
+        froms
+    );
+}
@@ -0,0 +1,25 @@
+use std::{fmt::Debug, sync::mpsc};
+
+use zond::{CollectionInfo, Operation, OperationType, Operations, ZondHandler};
+
+pub struct Handler<T: OperationType>(pub mpsc::Sender<(usize, Operation<T>)>);
+
+impl<T: OperationType + Debug + Send> ZondHandler<T> for Handler<T> {
+    fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {
+        for operation in operations {
+            self.0.send((collection.get_id(), operation)).unwrap();
+        }
+    }
+}
+
+// Collects types of all operations that were sent to the `Handler`.
+// Not every test binary uses it.
+#[allow(dead_code)]
+pub fn collect_types<T: OperationType + Clone>(
+    reciever: mpsc::Receiver<(usize, Operation<T>)>,
+) -> Vec<T> {
+    reciever
+        .into_iter()
+        .map(|v| v.1.get_type().clone())
+        .collect()
+}
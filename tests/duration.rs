@@ -0,0 +1,67 @@
+use std::{sync::mpsc, thread, time::Duration};
+
+use zond::{zvec::ZVec, OperationType, Policy, Zond};
+
+mod common;
+
+use common::Handler;
+
+#[test]
+pub fn duration() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+    let mut zvec: ZVec<usize> = ZVec::new(zond.clone().with_duration());
+    zvec.push(2);
+    zvec.push(1);
+    zvec.retain(|_| {
+        thread::sleep(Duration::from_millis(10));
+        true
+    });
+    zvec.as_zslice_mut().sort();
+    drop(zvec);
+
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    zvec.push(1);
+    drop(zvec);
+
+    let durations: Vec<_> = reciever
+        .into_iter()
+        .map(|(_, operation)| operation.get_duration())
+        .collect();
+    // Constructors aren't measured.
+    assert_eq!(None, durations[0]);
+    assert!(durations[1..4].iter().all(Option::is_some));
+    assert!(durations[3].unwrap() >= Duration::from_millis(20));
+    assert!(durations[4].is_some());
+    // Measuring is off by default.
+    assert_eq!(vec![None, None], durations[5..]);
+}
+
+// Takes time to be dropped.
+#[derive(Debug)]
+struct Slow;
+
+impl Drop for Slow {
+    fn drop(&mut self) {
+        thread::sleep(Duration::from_millis(10));
+    }
+}
+
+#[test]
+pub fn duration_of_drain() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_duration();
+    let mut zvec: ZVec<Slow> = ZVec::from_vec(vec![Slow, Slow, Slow], zond);
+    // Remaining elements are dropped with the iterator.
+    zvec.drain(1..).next();
+    drop(zvec);
+
+    let durations: Vec<_> = reciever
+        .into_iter()
+        .map(|(_, operation)| (operation.get_type().kind(), operation.get_duration()))
+        .collect();
+    assert_eq!("Drain", durations[1].0);
+    assert!(durations[1].1.unwrap() >= Duration::from_millis(20));
+}
@@ -0,0 +1,50 @@
+use std::{collections::BTreeSet, sync::mpsc};
+
+use zond::{zvec::ZVec, IdGenerator, Ids, Policy, Zond};
+
+mod common;
+
+use common::Handler;
+
+// Constructs `count` collections and returns their ids in order of construction.
+fn ids(id_generator: impl IdGenerator + 'static, count: usize) -> Vec<usize> {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_id_generator(id_generator);
+    for _ in 0..count {
+        let zvec: ZVec<usize> = ZVec::new(zond.clone());
+        drop(zvec);
+    }
+    // Handler's sender lives in zond.
+    drop(zond);
+
+    reciever.into_iter().map(|(id, _)| id).collect()
+}
+
+#[test]
+pub fn counter() {
+    assert_eq!(vec![0, 1, 2], ids(Ids::counter(), 3));
+    assert_eq!(vec![0, 1, 2], ids(Ids::counter(), 3));
+}
+
+#[test]
+pub fn seeded() {
+    let first = ids(Ids::seeded(42), 10);
+    assert_eq!(first, ids(Ids::seeded(42), 10));
+    assert_ne!(first, ids(Ids::seeded(43), 10));
+    assert_eq!(10, first.iter().collect::<BTreeSet<_>>().len());
+}
+
+#[test]
+pub fn random() {
+    let first = ids(Ids::random(), 10);
+    assert_ne!(first, ids(Ids::random(), 10));
+    assert_eq!(10, first.iter().collect::<BTreeSet<_>>().len());
+}
+
+#[test]
+pub fn global() {
+    let first = ids(Ids::global(), 3);
+    let second = ids(Ids::global(), 3);
+    assert!(first.iter().all(|id| !second.contains(id)));
+}
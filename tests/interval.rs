@@ -0,0 +1,143 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    thread::{self, ThreadId},
+    time::{Duration, Instant},
+};
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    CollectionInfo, IntervalPolicy, Operations, Policy, Zond, ZondHandler,
+};
+
+// Sends only sizes of handled batches.
+struct BatchHandler(mpsc::Sender<usize>);
+
+impl ZondHandler<ZVecOperation<usize>> for BatchHandler {
+    fn handle(&self, _collection: &CollectionInfo, operations: Operations<ZVecOperation<usize>>) {
+        self.0.send(operations.len()).unwrap();
+    }
+}
+
+#[test]
+pub fn idle_collection_is_flushed_by_timer() {
+    let (sender, reciever) = mpsc::channel();
+    let policy = IntervalPolicy::new(Duration::from_millis(20), Policy::on_drop_only());
+    let mut zvec: ZVec<usize> = ZVec::new(Zond::new(BatchHandler(sender), policy));
+    zvec.push(1);
+    zvec.push(2);
+
+    // Collection isn't touched, but operations are handled anyway, possibly in several batches.
+    let mut handled = 0;
+    while handled < 3 {
+        let batch = reciever.recv_timeout(Duration::from_secs(5)).unwrap();
+        // Empty buffer isn't handled by timer.
+        assert_ne!(0, batch);
+        handled += batch;
+    }
+    drop(zvec);
+
+    assert_eq!(vec![0], reciever.try_iter().collect::<Vec<_>>());
+}
+
+// Panics on the first handling.
+struct PanickingHandler(mpsc::Sender<usize>, AtomicBool);
+
+impl ZondHandler<ZVecOperation<usize>> for PanickingHandler {
+    fn handle(&self, _collection: &CollectionInfo, operations: Operations<ZVecOperation<usize>>) {
+        if !self.1.swap(true, Ordering::Relaxed) {
+            panic!("handler failed");
+        }
+        self.0.send(operations.len()).unwrap();
+    }
+}
+
+#[test]
+pub fn handler_panic_does_not_stop_timer() {
+    let (sender, reciever) = mpsc::channel();
+    let policy = IntervalPolicy::new(Duration::from_millis(20), Policy::on_drop_only());
+    let mut zvec: ZVec<usize> = ZVec::new(Zond::new(
+        PanickingHandler(sender, AtomicBool::new(false)),
+        policy,
+    ));
+    // `New` is lost with the panic, but the next operation is still handled by timer.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while reciever.try_recv().is_err() {
+        assert!(
+            Instant::now() < deadline,
+            "operations aren't handled after panic"
+        );
+        zvec.push(1);
+        thread::sleep(Duration::from_millis(10));
+    }
+    drop(zvec);
+}
+
+// Reports that handling started and handles slowly.
+struct SlowHandler(mpsc::Sender<()>, mpsc::Sender<usize>);
+
+impl ZondHandler<ZVecOperation<usize>> for SlowHandler {
+    fn handle(&self, _collection: &CollectionInfo, operations: Operations<ZVecOperation<usize>>) {
+        if !operations.is_empty() {
+            self.0.send(()).unwrap();
+            thread::sleep(Duration::from_millis(50));
+        }
+        self.1.send(operations.len()).unwrap();
+    }
+}
+
+#[test]
+pub fn drop_waits_for_timer_handling() {
+    let (started_sender, started_reciever) = mpsc::channel();
+    let (sender, reciever) = mpsc::channel();
+    let policy = IntervalPolicy::new(Duration::from_millis(10), Policy::on_drop_only());
+    let zvec: ZVec<usize> = ZVec::new(Zond::new(SlowHandler(started_sender, sender), policy));
+
+    started_reciever
+        .recv_timeout(Duration::from_secs(5))
+        .unwrap();
+    // Timer's handling is in progress, so the last handling must wait for it.
+    drop(zvec);
+
+    assert_eq!(vec![1, 0], reciever.try_iter().collect::<Vec<_>>());
+}
+
+// Sends sequence numbers of handled operations. Handling by timer is reported and slow.
+struct SequenceHandler(mpsc::Sender<()>, mpsc::Sender<Vec<u64>>, ThreadId);
+
+impl ZondHandler<ZVecOperation<usize>> for SequenceHandler {
+    fn handle(&self, _collection: &CollectionInfo, operations: Operations<ZVecOperation<usize>>) {
+        if thread::current().id() != self.2 {
+            self.0.send(()).unwrap();
+            thread::sleep(Duration::from_millis(50));
+        }
+        let sequences = operations.iter().map(|v| v.get_sequence()).collect();
+        self.1.send(sequences).unwrap();
+    }
+}
+
+#[test]
+pub fn timer_and_policy_deliver_in_order() {
+    let (started_sender, started_reciever) = mpsc::channel();
+    let (sender, reciever) = mpsc::channel();
+    let policy = IntervalPolicy::new(
+        Duration::from_millis(10),
+        Policy::on_count_operations(NonZeroUsize::new(2).unwrap()),
+    );
+    let handler = SequenceHandler(started_sender, sender, thread::current().id());
+    let mut zvec: ZVec<usize> = ZVec::new(Zond::new(handler, policy));
+
+    started_reciever
+        .recv_timeout(Duration::from_secs(5))
+        .unwrap();
+    // Timer is handling `New`, so policy's handling must wait for it.
+    zvec.push(1);
+    zvec.push(2);
+    drop(zvec);
+
+    let sequences: Vec<u64> = reciever.try_iter().flatten().collect();
+    assert_eq!(vec![0, 1, 2], sequences);
+}
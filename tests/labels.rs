@@ -0,0 +1,50 @@
+use std::sync::mpsc;
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    CollectionInfo, Operations, Policy, Zond, ZondHandler,
+};
+
+// Sends info about collections whose operations were handled.
+struct InfoHandler(mpsc::Sender<CollectionInfo>);
+
+impl ZondHandler<ZVecOperation<usize>> for InfoHandler {
+    fn handle(&self, collection: &CollectionInfo, _operations: Operations<ZVecOperation<usize>>) {
+        self.0.send(collection.clone()).unwrap();
+    }
+}
+
+#[test]
+pub fn names_and_labels() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(InfoHandler(sender), Policy::on_drop_only());
+    let named = zond
+        .clone()
+        .with_name("user_cache")
+        .with_label("region", "eu")
+        .with_label("shard", "1")
+        .with_label("shard", "2");
+
+    let unnamed: ZVec<usize> = ZVec::new(zond);
+    let first: ZVec<usize> = ZVec::new(named.clone());
+    let second: ZVec<usize> = ZVec::new(named);
+    drop(unnamed);
+    drop(first);
+    drop(second);
+
+    let infos: Vec<_> = reciever.into_iter().collect();
+    assert_eq!(None, infos[0].get_name());
+    assert!(infos[0].get_labels().is_empty());
+    for info in &infos[1..] {
+        assert_eq!(Some("user_cache"), info.get_name());
+        assert_eq!(
+            vec![("region", "eu"), ("shard", "2")],
+            info.get_labels()
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+    assert_ne!(infos[1].get_id(), infos[2].get_id());
+}
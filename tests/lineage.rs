@@ -0,0 +1,126 @@
+use std::{ops::Bound, sync::mpsc};
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    Capture, Captured, CapturedSlice, Ids, Policy, Zond,
+};
+
+mod common;
+
+use common::Handler;
+
+#[test]
+pub fn lineage() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only())
+        .with_capture(Capture::value())
+        .with_id_generator(Ids::counter());
+
+    let mut zvec: ZVec<usize> = ZVec::from_vec(vec![1, 2, 3, 4, 5], zond);
+    let mut split = zvec.split_off_zvec(3);
+    let mut drained = zvec.drain_zvec(..1);
+    assert_eq!(&[2, 3], zvec.as_slice());
+    assert_eq!(&[4, 5], split.as_slice());
+    assert_eq!(&[1], drained.as_slice());
+    drained.append_zvec(&mut split);
+    assert_eq!(&[1, 4, 5], drained.as_slice());
+    assert!(split.as_slice().is_empty());
+    drop(zvec);
+    drop(split);
+    drop(drained);
+
+    let mut operations: Vec<_> = reciever
+        .into_iter()
+        .map(|(id, operation)| (id, format!("{:?}", operation.get_type())))
+        .collect();
+    operations.sort_by_key(|(id, _)| *id);
+    let values = |values: &[usize]| -> CapturedSlice<usize> {
+        CapturedSlice::Items(
+            values
+                .iter()
+                .map(|value| Captured::Value(Box::new(*value)))
+                .collect(),
+        )
+    };
+    assert_eq!(
+        vec![
+            (
+                0,
+                format!(
+                    "{:?}",
+                    ZVecOperation::FromVec {
+                        from: values(&[1, 2, 3, 4, 5])
+                    }
+                )
+            ),
+            (
+                0,
+                format!(
+                    "{:?}",
+                    ZVecOperation::<usize>::SplitOffZVec {
+                        at: 3,
+                        child: 1,
+                        removed: 2
+                    }
+                )
+            ),
+            (
+                0,
+                format!(
+                    "{:?}",
+                    ZVecOperation::<usize>::DrainZVec {
+                        start_bound: Bound::Unbounded,
+                        end_bound: Bound::Excluded(1),
+                        child: 2,
+                        removed: 1
+                    }
+                )
+            ),
+            (0, format!("{:?}", ZVecOperation::<usize>::AsSlice)),
+            (
+                1,
+                format!(
+                    "{:?}",
+                    ZVecOperation::<usize>::FromSplitOff { parent: 0, at: 3 }
+                )
+            ),
+            (1, format!("{:?}", ZVecOperation::<usize>::AsSlice)),
+            (
+                1,
+                format!(
+                    "{:?}",
+                    ZVecOperation::<usize>::AppendedTo {
+                        target: 2,
+                        removed: 2
+                    }
+                )
+            ),
+            (1, format!("{:?}", ZVecOperation::<usize>::AsSlice)),
+            (
+                2,
+                format!(
+                    "{:?}",
+                    ZVecOperation::<usize>::FromDrain {
+                        parent: 0,
+                        start_bound: Bound::Unbounded,
+                        end_bound: Bound::Excluded(1)
+                    }
+                )
+            ),
+            (2, format!("{:?}", ZVecOperation::<usize>::AsSlice)),
+            (
+                2,
+                format!(
+                    "{:?}",
+                    ZVecOperation::Append {
+                        other: values(&[4, 5]),
+                        source: Some(1)
+                    }
+                )
+            ),
+            (2, format!("{:?}", ZVecOperation::<usize>::AsSlice)),
+        ],
+        operations
+    );
+}
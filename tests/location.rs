@@ -0,0 +1,97 @@
+use std::{num::NonZeroUsize, sync::mpsc};
+
+use zond::{
+    zhashmap::ZHashMap,
+    zvec::{ZVec, ZVecOperation},
+    Policy, Zond,
+};
+
+mod common;
+
+use common::Handler;
+
+#[test]
+pub fn locations() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(
+        Handler(sender),
+        Policy::on_count_operations(NonZeroUsize::new(2).unwrap()),
+    )
+    .with_locations();
+
+    let new_line = line!() + 1;
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    let push_line = line!() + 1;
+    zvec.push(1);
+    let resize_line = line!() + 1;
+    zvec.resize(3, 0);
+    let index_line = line!() + 1;
+    let _ = zvec[0];
+    let len_line = line!() + 1;
+    let _ = zvec.len();
+    let into_vec_line = line!() + 1;
+    let _: Vec<usize> = zvec.into();
+
+    let locations: Vec<_> = reciever
+        .into_iter()
+        .map(|(_, operation)| {
+            assert!(operation.get_backtrace().is_none());
+            operation.get_location().unwrap()
+        })
+        .collect();
+    assert!(locations
+        .iter()
+        .all(|location| location.file().ends_with("location.rs")));
+    assert_eq!(
+        vec![
+            new_line,
+            push_line,
+            resize_line,
+            index_line,
+            len_line,
+            into_vec_line
+        ],
+        locations
+            .iter()
+            .map(|location| location.line())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+pub fn backtraces() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_backtraces();
+
+    let mut zhashmap: ZHashMap<usize, usize> = ZHashMap::new(zond);
+    zhashmap.insert(1, 1);
+    drop(zhashmap);
+
+    for (_, operation) in reciever {
+        assert!(operation.get_location().is_none());
+        assert!(operation.get_backtrace().is_some());
+    }
+}
+
+#[test]
+pub fn no_locations_by_default() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    zvec.push(1);
+    drop(zvec);
+
+    let operations: Vec<_> = reciever.into_iter().collect();
+    assert!(matches!(
+        operations[1].1.get_type(),
+        ZVecOperation::Push { .. }
+    ));
+    for (_, operation) in operations {
+        assert!(operation.get_location().is_none());
+        assert!(operation.get_backtrace().is_none());
+    }
+}
@@ -0,0 +1,123 @@
+use std::{num::NonZeroUsize, sync::mpsc, thread, time::SystemTime};
+
+use zond::{zvec::ZVec, OverflowStrategy, Policy, Zond};
+
+mod common;
+
+use common::Handler;
+
+#[test]
+pub fn sequence() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(
+        Handler(sender),
+        Policy::on_count_operations(NonZeroUsize::new(2).unwrap()),
+    );
+
+    let mut first: ZVec<usize> = ZVec::new(zond.clone());
+    let mut second: ZVec<usize> = ZVec::new(zond);
+    first.push(1);
+    second.push(1);
+    first.push(2);
+    drop(first);
+    drop(second);
+
+    let mut sequences: Vec<_> = reciever
+        .into_iter()
+        .map(|(id, operation)| (id, operation.get_sequence()))
+        .collect();
+    sequences.sort();
+    let first_id = sequences[0].0;
+    let second_id = sequences[3].0;
+    assert_eq!(
+        vec![
+            (first_id, 0),
+            (first_id, 1),
+            (first_id, 2),
+            (second_id, 0),
+            (second_id, 1)
+        ],
+        sequences
+    );
+}
+
+#[test]
+pub fn sequence_gaps_on_dropped_operations() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only())
+        .with_buffer_limit(NonZeroUsize::new(2).unwrap(), OverflowStrategy::DropOldest);
+
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    zvec.push(1);
+    zvec.push(2);
+    zvec.push(3);
+    drop(zvec);
+
+    let sequences: Vec<_> = reciever
+        .into_iter()
+        .map(|(_, operation)| operation.get_sequence())
+        .collect();
+    assert_eq!(vec![2, 3], sequences);
+}
+
+#[test]
+pub fn system_time_and_threads() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only())
+        .with_system_time()
+        .with_threads();
+
+    let before = SystemTime::now();
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    let zvec = thread::Builder::new()
+        .name("pusher".to_string())
+        .spawn(move || {
+            zvec.push(1);
+            zvec
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    let _ = zvec.len();
+    drop(zvec);
+    let after = SystemTime::now();
+
+    let operations: Vec<_> = reciever.into_iter().map(|(_, v)| v).collect();
+    for operation in &operations {
+        let system_time = operation.get_system_time().unwrap();
+        assert!(before <= system_time && system_time <= after);
+    }
+    let current = thread::current();
+    assert_eq!(
+        vec![
+            (Some(current.id()), current.name()),
+            (operations[1].get_thread_id(), Some("pusher")),
+            (Some(current.id()), current.name()),
+        ],
+        operations
+            .iter()
+            .map(|operation| (operation.get_thread_id(), operation.get_thread_name()))
+            .collect::<Vec<_>>()
+    );
+    assert_ne!(Some(current.id()), operations[1].get_thread_id());
+}
+
+#[test]
+pub fn no_metadata_by_default() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    zvec.push(1);
+    drop(zvec);
+
+    for (_, operation) in reciever {
+        assert!(operation.get_system_time().is_none());
+        assert!(operation.get_thread_id().is_none());
+        assert!(operation.get_thread_name().is_none());
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Mutex,
+    },
+    time::Duration,
+};
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    CollectionHandle, CollectionInfo, FlushContext, FlushPolicy, Operation, Operations, Policy,
+    Zond, ZondHandler,
+};
+
+// Sends only sizes of handled batches.
+struct BatchHandler(mpsc::Sender<usize>);
+
+impl ZondHandler<ZVecOperation<usize>> for BatchHandler {
+    fn handle(&self, _collection: &CollectionInfo, operations: Operations<ZVecOperation<usize>>) {
+        self.0.send(operations.len()).unwrap();
+    }
+}
+
+// Checks `policy` against collection that has `buffered_operations` since `since_last_flush` ago.
+fn should_flush(policy: &Policy, buffered_operations: usize, since_last_flush: u64) -> bool {
+    let operation = Operation::new(ZVecOperation::<usize>::New);
+    policy.should_flush(&FlushContext::new(
+        buffered_operations,
+        Duration::from_millis(since_last_flush),
+        &operation,
+    ))
+}
+
+#[test]
+pub fn less_often() {
+    let policy = Policy::less_often(Duration::from_millis(30));
+    assert!(!should_flush(&policy, 10, 30));
+    assert!(should_flush(&policy, 1, 31));
+}
+
+#[test]
+pub fn any_of_fires_when_any_policy_fires() {
+    let policy = Policy::any_of([
+        Policy::on_count_operations(NonZeroUsize::new(4).unwrap()),
+        Policy::less_often(Duration::from_millis(30)),
+    ]);
+    assert!(!should_flush(&policy, 3, 10));
+    assert!(should_flush(&policy, 4, 10));
+    assert!(should_flush(&policy, 1, 50));
+}
+
+#[test]
+pub fn all_of_waits_for_every_policy() {
+    let policy = Policy::all_of([
+        Policy::on_count_operations(NonZeroUsize::new(2).unwrap()),
+        Policy::less_often(Duration::from_millis(30)),
+    ]);
+    assert!(!should_flush(&policy, 2, 10));
+    assert!(!should_flush(&policy, 1, 50));
+    assert!(should_flush(&policy, 2, 50));
+}
+
+#[test]
+pub fn any_of_resets_all_policies() {
+    let (sender, reciever) = mpsc::channel();
+    let policy = Policy::any_of([
+        Policy::on_count_operations(NonZeroUsize::new(2).unwrap()),
+        Policy::on_count_operations(NonZeroUsize::new(3).unwrap()),
+    ]);
+    let mut zvec: ZVec<usize> = ZVec::new(Zond::new(BatchHandler(sender), policy));
+    zvec.push(1);
+    zvec.push(2);
+    zvec.push(3);
+    drop(zvec);
+
+    // Second policy never fires, because counter starts over whenever first one fires.
+    assert_eq!(vec![2, 2, 0], reciever.try_iter().collect::<Vec<_>>());
+}
+
+#[test]
+pub fn not_inverts_policy() {
+    let (sender, reciever) = mpsc::channel();
+    let mut zvec: ZVec<usize> = ZVec::new(Zond::new(BatchHandler(sender), !Policy::on_drop_only()));
+    zvec.push(1);
+    drop(zvec);
+
+    assert_eq!(vec![1, 1, 0], reciever.try_iter().collect::<Vec<_>>());
+}
+
+// Handles operations right after the vector was cleared or when there are too many of them.
+struct OnClear;
+
+impl FlushPolicy<ZVecOperation<usize>> for OnClear {
+    fn should_flush(&self, context: &FlushContext<'_, ZVecOperation<usize>>) -> bool {
+        matches!(context.operation().get_type(), ZVecOperation::Clear { .. })
+            || context.buffered_operations() >= 5
+    }
+}
+
+#[test]
+pub fn custom_policy() {
+    let (sender, reciever) = mpsc::channel();
+    let mut zvec: ZVec<usize> = ZVec::new(Zond::new(BatchHandler(sender), OnClear));
+    zvec.push(1);
+    zvec.clear();
+    zvec.extend_from_slice(&[1, 2, 3, 4, 5]);
+    zvec.push(6);
+    zvec.push(7);
+    zvec.push(8);
+    zvec.push(9);
+    zvec.push(10);
+    drop(zvec);
+
+    assert_eq!(vec![3, 5, 1], reciever.try_iter().collect::<Vec<_>>());
+}
+
+// Panics on the first push.
+struct PanickingPolicy(AtomicBool);
+
+impl FlushPolicy<ZVecOperation<usize>> for PanickingPolicy {
+    fn should_flush(&self, context: &FlushContext<'_, ZVecOperation<usize>>) -> bool {
+        if matches!(context.operation().get_type(), ZVecOperation::Push { .. })
+            && !self.0.swap(true, Ordering::Relaxed)
+        {
+            panic!("policy failed");
+        }
+        false
+    }
+}
+
+#[test]
+pub fn policy_panic_does_not_break_collection() {
+    let (sender, reciever) = mpsc::channel();
+    let zond = Zond::new(
+        BatchHandler(sender),
+        PanickingPolicy(AtomicBool::new(false)),
+    );
+    let mut zvec: ZVec<usize> = ZVec::new(zond.clone());
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| zvec.push(1))).is_err());
+    zvec.push(2);
+    drop(zvec);
+
+    // `Push { value: 1 }` is lost with the panic.
+    assert_eq!(vec![2], reciever.try_iter().collect::<Vec<_>>());
+}
+
+// Flushes collections itself instead of returning `true`.
+struct SelfFlushing(Mutex<Vec<CollectionHandle<ZVecOperation<usize>>>>);
+
+impl FlushPolicy<ZVecOperation<usize>> for SelfFlushing {
+    fn should_flush(&self, _context: &FlushContext<'_, ZVecOperation<usize>>) -> bool {
+        for collection in self.0.lock().unwrap().iter() {
+            collection.flush();
+        }
+        false
+    }
+
+    fn register(&self, collection: CollectionHandle<ZVecOperation<usize>>) {
+        self.0.lock().unwrap().push(collection);
+    }
+}
+
+#[test]
+pub fn policy_flushes_collection_itself() {
+    let (sender, reciever) = mpsc::channel();
+    let mut zvec: ZVec<usize> = ZVec::new(Zond::new(
+        BatchHandler(sender),
+        SelfFlushing(Mutex::new(Vec::new())),
+    ));
+    zvec.push(1);
+    zvec.push(2);
+    drop(zvec);
+
+    // Each check flushes operations saved before it.
+    assert_eq!(vec![1, 1, 1], reciever.try_iter().collect::<Vec<_>>());
+}
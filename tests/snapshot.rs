@@ -0,0 +1,119 @@
+use std::sync::mpsc;
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    OperationType, Policy, Zond,
+};
+
+mod common;
+
+use common::Handler;
+
+#[test]
+pub fn snapshot() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_snapshots();
+    let mut zvec: ZVec<usize> = ZVec::with_capacity(1, zond);
+    zvec.push(1);
+    zvec.push(2);
+    zvec.len();
+    zvec.truncate(1);
+    drop(zvec);
+
+    let operations: Vec<_> = reciever
+        .into_iter()
+        .map(|(_, operation)| {
+            let snapshot = operation.get_snapshot().map(|snapshot| {
+                (
+                    snapshot.get_len_before(),
+                    snapshot.get_len_after(),
+                    snapshot.get_capacity_before() == snapshot.get_capacity_after(),
+                )
+            });
+            (operation.get_type().kind(), snapshot)
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("WithCapacity", None),
+            ("Push", Some((0, 1, true))),
+            ("Push", Some((1, 2, false))),
+            ("Reallocated", None),
+            ("Len", None),
+            ("Truncate", Some((2, 1, true))),
+        ],
+        operations
+    );
+}
+
+#[test]
+pub fn reallocated() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_snapshots();
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    zvec.reserve_exact(4);
+    zvec.shrink_to(2);
+    drop(zvec);
+
+    let reallocations: Vec<_> = reciever
+        .into_iter()
+        .filter_map(|(_, operation)| match *operation.get_type() {
+            ZVecOperation::Reallocated {
+                old_capacity,
+                new_capacity,
+            } => Some((old_capacity, new_capacity)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![(0, 4), (4, 2)], reallocations);
+}
+
+#[test]
+pub fn reallocated_by_splice() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_snapshots();
+    let mut zvec: ZVec<usize> = ZVec::with_capacity(2, zond);
+    zvec.push(1);
+    // Vector grows when `Splice` is dropped.
+    zvec.splice(1.., 2..10);
+    zvec.truncate(1);
+    drop(zvec);
+
+    let kinds: Vec<_> = reciever
+        .into_iter()
+        .map(|(_, operation)| operation.get_type().kind())
+        .collect();
+    assert_eq!(
+        vec!["WithCapacity", "Push", "Splice", "Reallocated", "Truncate"],
+        kinds
+    );
+}
+
+#[test]
+pub fn reallocated_by_last_splice() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_snapshots();
+    let mut zvec: ZVec<usize> = ZVec::with_capacity(2, zond);
+    zvec.push(1);
+    zvec.splice(1.., 2..10);
+    drop(zvec);
+
+    let operations: Vec<_> = reciever
+        .into_iter()
+        .skip(2)
+        .map(|(_, operation)| {
+            let snapshot = operation
+                .get_snapshot()
+                .map(|snapshot| (snapshot.get_len_before(), snapshot.get_len_after()));
+            (operation.get_type().kind(), snapshot)
+        })
+        .collect();
+    assert_eq!(
+        vec![("Splice", Some((1, 9))), ("Reallocated", None)],
+        operations
+    );
+}
@@ -0,0 +1,154 @@
+use std::{collections::BTreeMap, num::NonZeroUsize, sync::mpsc};
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    Capture, CollectionInfo, Operations, Policy, Zond, ZondHandler, ZondSummary,
+};
+
+struct SummaryHandler(mpsc::Sender<ZondSummary>);
+
+impl ZondHandler<ZVecOperation<usize>> for SummaryHandler {
+    fn handle(&self, _collection: &CollectionInfo, _operations: Operations<ZVecOperation<usize>>) {
+        panic!("operations mustn't be handled with summary");
+    }
+
+    fn handle_summary(&self, _collection: &CollectionInfo, summary: &ZondSummary) {
+        self.0.send(summary.clone()).unwrap();
+    }
+}
+
+#[test]
+pub fn summary() {
+    let (sender, reciever) = mpsc::channel();
+    let zond = Zond::new(SummaryHandler(sender), Policy::on_drop_only()).with_summary();
+    let mut zvec: ZVec<usize> = ZVec::with_capacity(2, zond);
+    zvec.push(1);
+    zvec.push(2);
+    zvec.reserve(10);
+    zvec.reserve(4);
+    zvec.insert(0, 3);
+    zvec.insert(2, 4);
+    zvec.pop();
+    drop(zvec);
+
+    let summary = reciever.recv().unwrap();
+    assert_eq!(8, summary.get_count());
+    assert_eq!(
+        vec!["Insert", "Pop", "Push", "Reserve", "WithCapacity"],
+        summary
+            .get_kinds()
+            .map(|(kind, _)| kind)
+            .collect::<Vec<_>>()
+    );
+    let kinds: BTreeMap<_, _> = summary.get_kinds().collect();
+    assert_eq!(2, kinds["Push"].get_count());
+    assert!(kinds["Push"].get_arguments().is_empty());
+    let additional = kinds["Reserve"].get_arguments()["additional"];
+    assert_eq!(
+        (14, 4, 10),
+        (
+            additional.get_sum(),
+            additional.get_min(),
+            additional.get_max()
+        )
+    );
+    let index = kinds["Insert"].get_arguments()["index"];
+    assert_eq!(
+        (2, 0, 2),
+        (index.get_sum(), index.get_min(), index.get_max())
+    );
+    assert_eq!(
+        2,
+        kinds["WithCapacity"].get_arguments()["capacity"].get_sum()
+    );
+}
+
+#[test]
+pub fn summary_of_slices() {
+    let (sender, reciever) = mpsc::channel();
+    let zond = Zond::new(SummaryHandler(sender), Policy::on_drop_only()).with_summary();
+    let mut zvec: ZVec<usize> = ZVec::from_vec(vec![3, 1, 2, 4], zond);
+    zvec.as_zslice_mut().sort();
+    let zslice = zvec.as_zslice();
+    assert_eq!(2, zslice.chunks(2).count());
+    assert_eq!(4, zslice.chunks(3).flatten().count());
+    assert_eq!(Ok(1), zslice.binary_search(&2));
+    drop(zvec);
+
+    let summary = reciever.recv().unwrap();
+    assert_eq!(
+        vec![
+            "FromVec",
+            "Slice::BinarySearch",
+            "Slice::Chunks",
+            "Slice::Sort"
+        ],
+        summary
+            .get_kinds()
+            .map(|(kind, _)| kind)
+            .collect::<Vec<_>>()
+    );
+    let chunk_size = summary.get_kind("Slice::Chunks").unwrap().get_arguments()["chunk_size"];
+    assert_eq!(
+        (5, 2, 3),
+        (
+            chunk_size.get_sum(),
+            chunk_size.get_min(),
+            chunk_size.get_max()
+        )
+    );
+}
+
+#[test]
+pub fn summary_is_reset_after_handling() {
+    let (sender, reciever) = mpsc::channel();
+    // Settings of what is saved with operations don't affect summary.
+    let zond = Zond::new(
+        SummaryHandler(sender),
+        Policy::on_count_operations(NonZeroUsize::new(3).unwrap()),
+    )
+    .with_summary()
+    .with_capture(Capture::value())
+    .with_duration()
+    .with_snapshots()
+    .with_locations()
+    .with_backtraces()
+    .with_system_time()
+    .with_threads();
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    zvec.reserve(10);
+    zvec.push(1);
+    zvec.push(2);
+    zvec.reserve(4);
+    zvec.truncate(1);
+    drop(zvec);
+
+    let summaries: Vec<ZondSummary> = reciever.try_iter().collect();
+    assert_eq!(3, summaries.len());
+    assert_eq!(
+        vec!["New", "Push", "Reserve"],
+        summaries[0]
+            .get_kinds()
+            .map(|(kind, _)| kind)
+            .collect::<Vec<_>>()
+    );
+    // Kinds from the previous summary aren't reported, arguments start over.
+    assert_eq!(
+        vec!["Push", "Reserve", "Truncate"],
+        summaries[1]
+            .get_kinds()
+            .map(|(kind, _)| kind)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(None, summaries[1].get_kind("New"));
+    let additional = summaries[1].get_kind("Reserve").unwrap().get_arguments()["additional"];
+    assert_eq!(
+        (4, 4, 4),
+        (
+            additional.get_sum(),
+            additional.get_min(),
+            additional.get_max()
+        )
+    );
+    assert_eq!(0, summaries[2].get_count());
+}
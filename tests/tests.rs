@@ -2,19 +2,29 @@ use std::{fmt::Debug, num::NonZeroUsize, ops::Bound, sync::mpsc};
 
 use zond::{
     zvec::{ZVec, ZVecOperation},
-    Operation, OperationType, Operations, Policy, Zond, ZondHandler,
+    Capture, Captured, CapturedSlice, CollectionInfo, Ids, Operation, OperationType, Operations,
+    Policy, Zond, ZondHandler,
 };
 
 struct Handler<T: OperationType>(mpsc::Sender<(usize, Operation<T>)>);
 
-impl<T: OperationType + Debug> ZondHandler<T> for Handler<T> {
-    fn handle(&self, id: usize, operations: Operations<T>) {
+impl<T: OperationType + Debug + Send> ZondHandler<T> for Handler<T> {
+    fn handle(&self, collection: &CollectionInfo, operations: Operations<T>) {
         for operation in operations {
-            self.0.send((id, operation)).unwrap();
+            self.0.send((collection.get_id(), operation)).unwrap();
         }
     }
 }
 
+fn values(values: &[usize]) -> CapturedSlice<usize> {
+    CapturedSlice::Items(
+        values
+            .iter()
+            .map(|value| Captured::Value(Box::new(*value)))
+            .collect(),
+    )
+}
+
 #[test]
 pub fn zvec() {
     let (sender, reciever) = mpsc::channel();
@@ -22,7 +32,9 @@ pub fn zvec() {
     let zond = Zond::new(
         Handler(sender),
         Policy::on_count_operations(NonZeroUsize::new(3).unwrap()),
-    );
+    )
+    .with_capture(Capture::value())
+    .with_id_generator(Ids::counter());
 
     let mut zvec: ZVec<usize> = ZVec::new(zond.clone());
     zvec.push(1);
@@ -52,10 +64,30 @@ pub fn zvec() {
             "{:?}",
             &[
                 (0, ZVecOperation::New),
-                (0, ZVecOperation::Push { value: 1 }),
-                (0, ZVecOperation::Push { value: 2 }),
-                (0, ZVecOperation::Push { value: 5 }),
-                (0, ZVecOperation::Push { value: 5 }),
+                (
+                    0,
+                    ZVecOperation::Push {
+                        value: Captured::Value(Box::new(1))
+                    }
+                ),
+                (
+                    0,
+                    ZVecOperation::Push {
+                        value: Captured::Value(Box::new(2))
+                    }
+                ),
+                (
+                    0,
+                    ZVecOperation::Push {
+                        value: Captured::Value(Box::new(5))
+                    }
+                ),
+                (
+                    0,
+                    ZVecOperation::Push {
+                        value: Captured::Value(Box::new(5))
+                    }
+                ),
                 (0, ZVecOperation::AsSlice),
                 (
                     0,
@@ -65,24 +97,24 @@ pub fn zvec() {
                     }
                 ),
                 (0, ZVecOperation::AsSlice),
-                (0, ZVecOperation::Dedup),
+                (0, ZVecOperation::Dedup { removed: 2 }),
                 (0, ZVecOperation::AsSlice),
                 (1, ZVecOperation::WithCapacity { capacity: 5 }),
-                (1, ZVecOperation::Len),
-                (1, ZVecOperation::Capacity),
+                (1, ZVecOperation::Len { outcome: 0 }),
+                (1, ZVecOperation::Capacity { outcome: 5 }),
                 (
                     1,
                     ZVecOperation::ExtendFromSlice {
-                        other: vec![1, 1, 2, 3, 5, 8, 13]
+                        other: values(&[1, 1, 2, 3, 5, 8, 13])
                     }
                 ),
                 (1, ZVecOperation::AsSlice),
-                (1, ZVecOperation::Clear),
-                (1, ZVecOperation::Len),
+                (1, ZVecOperation::Clear { removed: 7 }),
+                (1, ZVecOperation::Len { outcome: 0 }),
                 (
                     1,
                     ZVecOperation::ExtendFromSlice {
-                        other: vec![1, 1, 2, 3, 5, 8]
+                        other: values(&[1, 1, 2, 3, 5, 8])
                     }
                 ),
                 (1, ZVecOperation::IntoVec),
@@ -0,0 +1,105 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+use zond::{
+    zhashmap::ZHashMap,
+    zvec::{ZVec, ZVecOperation},
+    Capture, Captured, Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+pub fn collections_are_send_and_sync() {
+    assert_send_sync::<ZVec<usize>>();
+    assert_send_sync::<ZHashMap<String, usize>>();
+    assert_send_sync::<Zond<ZVecOperation<usize>>>();
+}
+
+#[test]
+pub fn zvec_moved_between_threads() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(
+        Handler(sender),
+        Policy::on_count_operations(NonZeroUsize::new(2).unwrap()),
+    )
+    .with_capture(Capture::value());
+
+    let mut zvec: ZVec<usize> = ZVec::new(zond);
+    zvec.push(1);
+    let mut zvec = thread::spawn(move || {
+        zvec.push(2);
+        zvec
+    })
+    .join()
+    .unwrap();
+    zvec.push(3);
+    drop(zvec);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZVecOperation::New,
+                ZVecOperation::Push {
+                    value: Captured::Value(Box::new(1))
+                },
+                ZVecOperation::Push {
+                    value: Captured::Value(Box::new(2))
+                },
+                ZVecOperation::Push {
+                    value: Captured::Value(Box::new(3))
+                },
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
+
+#[test]
+pub fn zvec_shared_between_threads() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(
+        Handler(sender),
+        Policy::on_count_operations(NonZeroUsize::new(3).unwrap()),
+    )
+    .with_capture(Capture::value());
+
+    let zvec: Arc<Mutex<ZVec<usize>>> = Arc::new(Mutex::new(ZVec::new(zond)));
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let zvec = Arc::clone(&zvec);
+            thread::spawn(move || {
+                for j in 0..25 {
+                    zvec.lock().unwrap().push(i * 25 + j);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(100, zvec.lock().unwrap().len());
+    drop(zvec);
+
+    let mut pushed: Vec<_> = collect_types(reciever)
+        .into_iter()
+        .filter_map(|operation| match operation {
+            ZVecOperation::Push {
+                value: Captured::Value(value),
+            } => Some(*value),
+            _ => None,
+        })
+        .collect();
+    pushed.sort();
+    assert_eq!((0..100).collect::<Vec<_>>(), pushed);
+}
@@ -0,0 +1,54 @@
+use std::sync::mpsc;
+
+use zond::{
+    zbinaryheap::{PeekMut, ZBinaryHeap, ZBinaryHeapOperation},
+    Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zbinaryheap() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zbinaryheap: ZBinaryHeap<usize> = ZBinaryHeap::new(zond);
+    zbinaryheap.push(3);
+    zbinaryheap.push(7);
+    zbinaryheap.push(5);
+    assert_eq!(Some(&7), zbinaryheap.peek());
+    {
+        let top = zbinaryheap.peek_mut().unwrap();
+        assert_eq!(7, *top);
+    }
+    {
+        let mut top = zbinaryheap.peek_mut().unwrap();
+        *top = 1;
+    }
+    assert_eq!(5, PeekMut::pop(zbinaryheap.peek_mut().unwrap()));
+    assert_eq!(vec![1, 3], zbinaryheap.into_sorted_vec());
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZBinaryHeapOperation::New,
+                ZBinaryHeapOperation::Push { item: 3 },
+                ZBinaryHeapOperation::Push { item: 7 },
+                ZBinaryHeapOperation::Push { item: 5 },
+                ZBinaryHeapOperation::Peek,
+                ZBinaryHeapOperation::PeekMut,
+                ZBinaryHeapOperation::PeekMutRelease { modified: false },
+                ZBinaryHeapOperation::PeekMut,
+                ZBinaryHeapOperation::PeekMutRelease { modified: true },
+                ZBinaryHeapOperation::PeekMut,
+                ZBinaryHeapOperation::PeekMutPop,
+                ZBinaryHeapOperation::IntoSortedVec,
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
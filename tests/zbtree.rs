@@ -0,0 +1,100 @@
+use std::{ops::Bound, sync::mpsc};
+
+use zond::{
+    zbtreemap::{ZBTreeMap, ZBTreeMapOperation},
+    zbtreeset::{ZBTreeSet, ZBTreeSetOperation},
+    Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zbtreemap() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zbtreemap: ZBTreeMap<usize, &str> = ZBTreeMap::new(zond);
+    zbtreemap.insert(1, "a");
+    zbtreemap.insert(5, "b");
+    zbtreemap.insert(10, "c");
+    assert_eq!(
+        vec![&"a", &"b"],
+        zbtreemap.range(..=5).map(|(_, v)| v).collect::<Vec<_>>()
+    );
+    assert_eq!(Some((10, "c")), zbtreemap.pop_last());
+    let split = zbtreemap.split_off(&5);
+    assert_eq!(1, split.len());
+    drop(zbtreemap);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZBTreeMapOperation::New,
+                ZBTreeMapOperation::Insert { key: 1, value: "a" },
+                ZBTreeMapOperation::Insert { key: 5, value: "b" },
+                ZBTreeMapOperation::Insert {
+                    key: 10,
+                    value: "c"
+                },
+                ZBTreeMapOperation::Range {
+                    start_bound: Bound::Unbounded,
+                    end_bound: Bound::Included(5)
+                },
+                ZBTreeMapOperation::PopLast,
+                ZBTreeMapOperation::SplitOff { key: 5 },
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
+
+#[test]
+pub fn zbtreeset() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zbtreeset: ZBTreeSet<String> = ZBTreeSet::new(zond);
+    zbtreeset.insert("apple".to_string());
+    zbtreeset.insert("banana".to_string());
+    zbtreeset.insert("cherry".to_string());
+    assert_eq!(
+        1,
+        zbtreeset
+            .range::<str, _>((Bound::Excluded("apple"), Bound::Excluded("cherry")))
+            .count()
+    );
+    assert_eq!(Some("apple".to_string()), zbtreeset.pop_first());
+    drop(zbtreeset);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZBTreeSetOperation::New,
+                ZBTreeSetOperation::Insert {
+                    value: "apple".to_string(),
+                    present: false
+                },
+                ZBTreeSetOperation::Insert {
+                    value: "banana".to_string(),
+                    present: false
+                },
+                ZBTreeSetOperation::Insert {
+                    value: "cherry".to_string(),
+                    present: false
+                },
+                ZBTreeSetOperation::Range {
+                    start_bound: Bound::Excluded("apple".to_string()),
+                    end_bound: Bound::Excluded("cherry".to_string())
+                },
+                ZBTreeSetOperation::PopFirst,
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
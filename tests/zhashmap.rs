@@ -0,0 +1,70 @@
+use std::sync::mpsc;
+
+use zond::{
+    zhashmap::{EntryState, ZHashMap, ZHashMapOperation},
+    Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zhashmap() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zhashmap: ZHashMap<String, usize> = ZHashMap::new(zond);
+    zhashmap.insert("one".to_string(), 1);
+    *zhashmap.entry("one".to_string()).or_insert(0) += 10;
+    zhashmap
+        .entry("two".to_string())
+        .and_modify(|v| *v += 1)
+        .or_default();
+    assert_eq!(Some(&11), zhashmap.get("one"));
+    assert!(zhashmap.contains_key("two"));
+    assert_eq!(None, zhashmap.remove("three"));
+    drop(zhashmap);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZHashMapOperation::New,
+                ZHashMapOperation::Insert {
+                    key: "one".to_string(),
+                    value: 1
+                },
+                ZHashMapOperation::Entry {
+                    key: "one".to_string(),
+                    state: EntryState::Occupied
+                },
+                ZHashMapOperation::EntryOrInsert {
+                    state: EntryState::Occupied,
+                    default: 0
+                },
+                ZHashMapOperation::Entry {
+                    key: "two".to_string(),
+                    state: EntryState::Vacant
+                },
+                ZHashMapOperation::EntryAndModify {
+                    state: EntryState::Vacant
+                },
+                ZHashMapOperation::EntryOrDefault {
+                    state: EntryState::Vacant
+                },
+                ZHashMapOperation::Get {
+                    key: "one".to_string()
+                },
+                ZHashMapOperation::ContainsKey {
+                    key: "two".to_string()
+                },
+                ZHashMapOperation::Remove {
+                    key: "three".to_string()
+                },
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
@@ -0,0 +1,58 @@
+use std::{collections::HashSet, sync::mpsc};
+
+use zond::{
+    zhashset::{ZHashSet, ZHashSetOperation},
+    Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zhashset() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zhashset: ZHashSet<String> = ZHashSet::new(zond);
+    assert!(zhashset.insert("a".to_string()));
+    assert!(!zhashset.insert("a".to_string()));
+    assert!(zhashset.contains("a"));
+    assert!(!zhashset.contains("b"));
+    let other = HashSet::from(["b".to_string()]);
+    assert_eq!(2, zhashset.union(&other).count());
+    assert!(zhashset.remove("a"));
+    drop(zhashset);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZHashSetOperation::New,
+                ZHashSetOperation::Insert {
+                    value: "a".to_string(),
+                    present: false
+                },
+                ZHashSetOperation::Insert {
+                    value: "a".to_string(),
+                    present: true
+                },
+                ZHashSetOperation::Contains {
+                    value: "a".to_string(),
+                    present: true
+                },
+                ZHashSetOperation::Contains {
+                    value: "b".to_string(),
+                    present: false
+                },
+                ZHashSetOperation::Union,
+                ZHashSetOperation::Remove {
+                    value: "a".to_string(),
+                    present: true
+                },
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
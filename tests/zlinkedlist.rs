@@ -0,0 +1,46 @@
+use std::{collections::LinkedList, sync::mpsc};
+
+use zond::{
+    zlinkedlist::{ZLinkedList, ZLinkedListOperation},
+    Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zlinkedlist() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zlinkedlist: ZLinkedList<usize> = ZLinkedList::new(zond);
+    zlinkedlist.push_back(2);
+    zlinkedlist.push_front(1);
+    zlinkedlist.append(&mut LinkedList::from([3, 4]));
+    assert!(zlinkedlist.contains(&3));
+    assert_eq!(LinkedList::from([3, 4]), zlinkedlist.split_off(2));
+    assert_eq!(3, zlinkedlist.iter().sum::<usize>());
+    assert_eq!(Some(2), zlinkedlist.pop_back());
+    drop(zlinkedlist);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZLinkedListOperation::New,
+                ZLinkedListOperation::PushBack { elt: 2 },
+                ZLinkedListOperation::PushFront { elt: 1 },
+                ZLinkedListOperation::Append {
+                    other: LinkedList::from([3, 4])
+                },
+                ZLinkedListOperation::Contains { x: 3 },
+                ZLinkedListOperation::SplitOff { at: 2 },
+                ZLinkedListOperation::Iter,
+                ZLinkedListOperation::PopBack,
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
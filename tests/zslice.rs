@@ -0,0 +1,52 @@
+use std::sync::mpsc;
+
+use zond::{
+    zslice::ZSliceOperation,
+    zvec::{ZVec, ZVecOperation},
+    Capture, Captured, CapturedSlice, Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zslice() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_capture(Capture::debug());
+
+    let mut zvec: ZVec<usize> = ZVec::from_vec(vec![3, 1, 2], zond);
+    zvec.as_zslice_mut().sort();
+    let zslice = zvec.as_zslice();
+    assert_eq!(Ok(1), zslice.binary_search(&2));
+    assert!(zslice.contains(&3));
+    assert_eq!(Some(&1), zslice.first());
+    assert_eq!(2, zslice.chunks(2).count());
+    drop(zvec);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZVecOperation::FromVec {
+                    from: CapturedSlice::Items(
+                        ["3", "1", "2"]
+                            .map(|x| Captured::<usize>::Debug(x.to_string()))
+                            .to_vec()
+                    )
+                },
+                ZVecOperation::Slice(ZSliceOperation::Sort),
+                ZVecOperation::Slice(ZSliceOperation::BinarySearch {
+                    x: Captured::Debug("2".to_string())
+                }),
+                ZVecOperation::Slice(ZSliceOperation::Contains {
+                    x: Captured::Debug("3".to_string())
+                }),
+                ZVecOperation::Slice(ZSliceOperation::First),
+                ZVecOperation::Slice(ZSliceOperation::Chunks { chunk_size: 2 }),
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
@@ -0,0 +1,65 @@
+use std::{ops::Bound, sync::mpsc};
+
+use zond::{
+    zstring::{ZString, ZStringOperation},
+    Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zstring() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zstring = ZString::with_capacity(8, zond);
+    zstring.push_str("hello");
+    zstring.push(' ');
+    zstring.push_str("world");
+    zstring.insert_str(0, ">> ");
+    zstring.replace_range(3..8, "bye");
+    assert_eq!(">> bye world", zstring.as_str());
+    zstring.truncate(6);
+    let string: String = zstring.into();
+    assert_eq!(">> bye", string);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZStringOperation::WithCapacity { capacity: 8 },
+                ZStringOperation::PushStr { len: 5 },
+                ZStringOperation::Push { ch: ' ' },
+                ZStringOperation::PushStr { len: 5 },
+                ZStringOperation::InsertStr { idx: 0, len: 3 },
+                ZStringOperation::ReplaceRange {
+                    start_bound: Bound::Included(3),
+                    end_bound: Bound::Excluded(8),
+                    len: 3
+                },
+                ZStringOperation::AsStr,
+                ZStringOperation::Truncate { new_len: 6 },
+                ZStringOperation::IntoString,
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
+
+#[test]
+pub fn zstring_from_string() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let zstring = ZString::from_string(String::from("hello"), zond);
+    drop(zstring);
+
+    assert_eq!(
+        format!("{:?}", &[ZStringOperation::FromString { len: 5 }]),
+        format!("{:?}", collect_types(reciever))
+    );
+}
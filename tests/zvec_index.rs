@@ -0,0 +1,55 @@
+use std::{ops::Bound, sync::mpsc};
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    Capture, Captured, CapturedSlice, Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zvec_index() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_capture(Capture::value());
+
+    let mut zvec: ZVec<usize> = ZVec::from_vec(vec![1, 2, 3, 4], zond);
+    assert_eq!(2, zvec[1]);
+    zvec[0] = 10;
+    assert_eq!(&[10, 2], &zvec[..2]);
+    zvec[2..=3].fill(0);
+    assert_eq!(&[2, 0, 0], &zvec[(Bound::Excluded(0), Bound::Unbounded)]);
+    drop(zvec);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZVecOperation::FromVec {
+                    from: CapturedSlice::Items(
+                        [1, 2, 3, 4]
+                            .map(|value| Captured::Value(Box::new(value)))
+                            .to_vec()
+                    )
+                },
+                ZVecOperation::Index { index: 1 },
+                ZVecOperation::IndexMut { index: 0 },
+                ZVecOperation::IndexRange {
+                    start_bound: Bound::Unbounded,
+                    end_bound: Bound::Excluded(2)
+                },
+                ZVecOperation::IndexRangeMut {
+                    start_bound: Bound::Included(2),
+                    end_bound: Bound::Included(3)
+                },
+                ZVecOperation::IndexRange {
+                    start_bound: Bound::Excluded(0),
+                    end_bound: Bound::Unbounded
+                },
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
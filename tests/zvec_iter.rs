@@ -0,0 +1,82 @@
+use std::sync::mpsc;
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    CapturedSlice, Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zvec_iter() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zvec: ZVec<usize> = ZVec::from_vec(vec![1, 2, 3, 4], zond);
+    for value in &mut zvec {
+        *value *= 2;
+    }
+    for value in &zvec {
+        if *value > 2 {
+            break;
+        }
+    }
+    assert_eq!(
+        vec![8, 6],
+        zvec.into_iter().rev().take(2).collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                // Only count of elements is saved by default.
+                ZVecOperation::FromVec {
+                    from: CapturedSlice::<usize>::Count(4)
+                },
+                ZVecOperation::IterMut,
+                ZVecOperation::IterEnd {
+                    yielded: 4,
+                    exhausted: true
+                },
+                ZVecOperation::Iter,
+                ZVecOperation::IterEnd {
+                    yielded: 2,
+                    exhausted: false
+                },
+                ZVecOperation::IntoIter,
+                ZVecOperation::IterEnd {
+                    yielded: 2,
+                    exhausted: false
+                },
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}
+
+#[test]
+pub fn zvec_iter_zip() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let first: ZVec<usize> = ZVec::from_vec(vec![1, 2], zond.clone());
+    let second: ZVec<usize> = ZVec::from_vec(vec![3, 4], zond);
+    // `zip` stops after `None` from `first` and doesn't call `next` on `second` again.
+    assert_eq!(2, first.iter().zip(second.iter()).count());
+    drop(first);
+    drop(second);
+
+    let ends: Vec<_> = collect_types(reciever)
+        .into_iter()
+        .filter_map(|operation| match operation {
+            ZVecOperation::IterEnd { yielded, exhausted } => Some((yielded, exhausted)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![(2, true), (2, true)], ends);
+}
@@ -0,0 +1,50 @@
+use std::sync::mpsc;
+
+use zond::{
+    zvec::{ZVec, ZVecOperation},
+    Capture, Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zvec_outcome() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only()).with_capture(Capture::value());
+
+    let mut zvec: ZVec<usize> = ZVec::from_vec(vec![1, 2, 3, 4, 4], zond);
+    assert_eq!(4, zvec.swap_remove(3));
+    zvec.retain(|value| value % 2 == 0);
+    assert!(zvec.try_reserve(usize::MAX).is_err());
+    zvec.dedup();
+    assert_eq!(Some(4), zvec.pop());
+    assert_eq!(Some(2), zvec.pop());
+    assert_eq!(None, zvec.pop());
+    assert!(zvec.is_empty());
+    drop(zvec);
+
+    let operations: Vec<_> = collect_types(reciever)
+        .iter()
+        .skip(1)
+        .map(|operation| match operation {
+            ZVecOperation::TryReserve { outcome, .. } => format!("TryReserve {}", outcome.is_ok()),
+            operation => format!("{operation:?}"),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            "SwapRemove { index: 3, outcome: Value(4) }",
+            "Retain { removed: 2 }",
+            "TryReserve false",
+            "Dedup { removed: 0 }",
+            "Pop { outcome: Some(Value(4)) }",
+            "Pop { outcome: Some(Value(2)) }",
+            "Pop { outcome: None }",
+            "IsEmpty { outcome: true }",
+        ],
+        operations
+    );
+}
@@ -0,0 +1,51 @@
+use std::{ops::Bound, sync::mpsc};
+
+use zond::{
+    zvecdeque::{ZVecDeque, ZVecDequeOperation},
+    Policy, Zond,
+};
+
+mod common;
+
+use common::{collect_types, Handler};
+
+#[test]
+pub fn zvecdeque() {
+    let (sender, reciever) = mpsc::channel();
+
+    let zond = Zond::new(Handler(sender), Policy::on_drop_only());
+
+    let mut zvecdeque: ZVecDeque<usize> = ZVecDeque::with_capacity(4, zond);
+    zvecdeque.push_back(2);
+    zvecdeque.push_back(3);
+    zvecdeque.push_front(1);
+    zvecdeque.rotate_left(1);
+    assert_eq!(Some(&2), zvecdeque.front());
+    assert_eq!(Some(1), zvecdeque.pop_back());
+    assert_eq!(vec![3], zvecdeque.range(1..).copied().collect::<Vec<_>>());
+    assert!(zvecdeque.contains(&2));
+    zvecdeque.make_contiguous();
+    drop(zvecdeque);
+
+    assert_eq!(
+        format!(
+            "{:?}",
+            &[
+                ZVecDequeOperation::WithCapacity { capacity: 4 },
+                ZVecDequeOperation::PushBack { value: 2 },
+                ZVecDequeOperation::PushBack { value: 3 },
+                ZVecDequeOperation::PushFront { value: 1 },
+                ZVecDequeOperation::RotateLeft { n: 1 },
+                ZVecDequeOperation::Front,
+                ZVecDequeOperation::PopBack,
+                ZVecDequeOperation::Range {
+                    start_bound: Bound::Included(1),
+                    end_bound: Bound::Unbounded
+                },
+                ZVecDequeOperation::Contains { x: 2 },
+                ZVecDequeOperation::MakeContiguous,
+            ]
+        ),
+        format!("{:?}", collect_types(reciever))
+    );
+}